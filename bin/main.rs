@@ -172,23 +172,46 @@ fn main() {
         match *result {
             ItemClass::Ok(Item::NonOption(i, s)) => printer(i, "NonOption", s),
             ItemClass::Ok(Item::EarlyTerminator(i)) => printer(i, "EarlyTerminator", ""),
+            ItemClass::Ok(Item::PassThrough(i, s)) => printer(i, "PassThrough", s),
             ItemClass::Ok(Item::Long(i, n)) => printer(i, "Long", n),
-            ItemClass::Ok(Item::LongWithData { i, n, d, ref l }) => {
+            ItemClass::Ok(Item::LongNegated(i, n)) => printer(i, "LongNegated", n),
+            ItemClass::Ok(Item::LongWithData { i, n, d, v, ref l }) => {
                 printer(i, "LongWithData", n);
                 print_data(*l, d);
+                if v != d {
+                    println!("    value: {}", v);
+                }
             },
             ItemClass::Err(ItemE::LongMissingData(i, n)) => printer(i, "LongMissingData", n),
+            ItemClass::Err(ItemE::LongInvalidData { i, n, d, ref l, v }) => {
+                printer(i, "LongInvalidData", n);
+                print_data(*l, d);
+                println!("    possible values: {}", v.join(", "));
+            },
             ItemClass::Warn(ItemW::LongWithUnexpectedData { i, n, d }) => {
                 printer(i, "LongWithUnexpectedData", n);
                 println!("\n    data: {}", d)
             },
             ItemClass::Err(ItemE::AmbiguousLong(i, n)) => printer(i, "AmbiguousLong", n),
             ItemClass::Warn(ItemW::LongWithNoName(i)) => printer(i, "LongWithNoName", ""),
+            ItemClass::Warn(ItemW::LateOption(i)) => printer(i, "LateOption", ""),
             ItemClass::Warn(ItemW::UnknownLong(i, n)) => printer(i, "UnknownLong", n),
+            ItemClass::Warn(ItemW::LongNotNegatable(i, n)) => printer(i, "LongNotNegatable", n),
+            ItemClass::Warn(ItemW::LongAbbreviated(i, n)) => printer(i, "LongAbbreviated", n),
+            ItemClass::Warn(ItemW::LongDeprecated(i, n, r)) => {
+                printer(i, "LongDeprecated", n);
+                if let Some(r) = r {
+                    println!("    replacement: {}", r);
+                }
+            },
             ItemClass::Ok(Item::Short(i, c)) => {
                 let desc = desc_char(c);
                 printer(i, "Short", &desc);
             },
+            ItemClass::Ok(Item::ShortNegated(i, c)) => {
+                let desc = desc_char(c);
+                printer(i, "ShortNegated", &desc);
+            },
             ItemClass::Ok(Item::ShortWithData { i, c, d, ref l }) => {
                 let desc = desc_char(c);
                 printer(i, "ShortWithData", &desc);
@@ -202,6 +225,17 @@ fn main() {
                 let desc = desc_char(c);
                 printer(i, "UnknownShort", &desc);
             },
+            ItemClass::Warn(ItemW::ShortNotNegatable(i, c)) => {
+                let desc = desc_char(c);
+                printer(i, "ShortNotNegatable", &desc);
+            },
+            ItemClass::Warn(ItemW::ShortDeprecated(i, c, r)) => {
+                let desc = desc_char(c);
+                printer(i, "ShortDeprecated", &desc);
+                if let Some(r) = r {
+                    println!("    replacement: {}", r);
+                }
+            },
         }
     }
     if results.items.len() != 0 {
@@ -0,0 +1,73 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Benchmarks comparing option lookup with plain versus compiled option sets
+//!
+//! Run with `cargo bench`. This uses a simple self-contained harness (no external dependencies),
+//! reporting the average time taken to process a set of arguments against generated option sets
+//! of increasing size.
+
+#[macro_use]
+extern crate gong;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use gong::compiled::CompiledOptionSet;
+use gong::options::*;
+
+/// Option set sizes to compare
+const SIZES: &[usize] = &[10, 100, 1000, 5000];
+
+/// Minimum time to spend on each measurement
+const MEASURE_TIME: Duration = Duration::from_millis(500);
+
+/// Runs `f` repeatedly, returning the average time taken per run
+fn measure<F: FnMut()>(mut f: F) -> Duration {
+    let mut runs = 0u32;
+    let start = Instant::now();
+    loop {
+        f();
+        runs += 1;
+        let elapsed = start.elapsed();
+        if elapsed >= MEASURE_TIME {
+            return elapsed / runs;
+        }
+    }
+}
+
+fn main() {
+    println!("{:>8}  {:>12}  {:>12}  {:>8}", "options", "plain", "compiled", "speedup");
+    for &size in SIZES {
+        let names: Vec<String> = (0..size).map(|i| format!("option-{}", i)).collect();
+        let mut opts = OptionSetEx::new(size, size);
+        for (i, name) in names.iter().enumerate() {
+            opts.long.push(gong_longopt!(name, i % 4 == 0));
+        }
+        let other = (0..size as u32).filter_map(|i| std::char::from_u32(0x100 + i));
+        for ch in ('a'..='z').chain(other) {
+            opts.short.push(gong_shortopt!(ch));
+        }
+        let opts = opts.as_fixed();
+        assert!(opts.is_valid());
+        let compiled = CompiledOptionSet::new(&opts);
+
+        // A mix of exact names (from across the set), abbreviations, unknowns and short option sets
+        let mut args: Vec<String> = (0..size).step_by(size / 10).map(|i| format!("--{}", names[i]))
+            .collect();
+        args.extend(["--option-1", "--opt", "--unknown", "-abc", "-xyz", "-ÿ", "-€"].iter()
+            .map(|s| s.to_string()));
+
+        assert_eq!(compiled.process(&args), opts.process(&args));
+        let plain = measure(|| { black_box(opts.process(black_box(&args))); });
+        let indexed = measure(|| { black_box(compiled.process(black_box(&args))); });
+        println!("{:>8}  {:>12?}  {:>12?}  {:>7.1}x", size, plain, indexed,
+            plain.as_secs_f64() / indexed.as_secs_f64());
+    }
+}
@@ -10,6 +10,9 @@
 
 //! Analysis components
 
+use std::ops::Range;
+use super::options::{OptionsMode, Positional, Syntax};
+
 /// Analysis of processing arguments against an option set
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Analysis<'a> {
@@ -63,12 +66,22 @@ pub enum Item<'a> {
     EarlyTerminator(usize),
     /// Long option match.
     Long(usize, &'a str),
-    /// Long option match, with expected data argument.
-    LongWithData{ i: usize, n: &'a str, d: &'a str, l: DataLocation },
+    /// Long option match, negated (e.g. `++foo` with plus prefix support, or `/flag-` in DOS mode).
+    LongNegated(usize, &'a str),
+    /// Long option match, with expected data argument. The data value is given as provided (`d`)
+    /// and as resolved (`v`), the latter being the full possible value matched, where the option
+    /// declares possible values, otherwise the same as the former.
+    LongWithData{ i: usize, n: &'a str, d: &'a str, v: &'a str, l: DataLocation },
     /// Short option match.
     Short(usize, char),
+    /// Short option match, negated (e.g. `+x` with plus prefix support).
+    ShortNegated(usize, char),
     /// Short option match, with expected data argument.
     ShortWithData{ i: usize, c: char, d: &'a str, l: DataLocation },
+    /// Argument holding an unknown option, passed through verbatim. This only occurs with
+    /// pass-through enabled in the option set, in place of unknown option items. (A *short option*
+    /// set containing any unknown option is passed through as a whole).
+    PassThrough(usize, &'a str),
 }
 
 /// Error-level items. See [`ItemClass`](enum.ItemClass.html) documentation for details.
@@ -81,6 +94,10 @@ pub enum ItemE<'a> {
     /// Ambiguous match with multiple long options. This only occurs when an exact match was not
     /// found, but multiple  abbreviated possible matches were found. [ERROR]
     AmbiguousLong(usize, &'a str),
+    /// Long option match, but the data value is not one of the option’s possible values (nor an
+    /// unambiguous abbreviation of one, where abbreviations are allowed). The possible values are
+    /// included for use in error messages. [ERROR]
+    LongInvalidData{ i: usize, n: &'a str, d: &'a str, l: DataLocation, v: &'a [&'a str] },
 }
 
 /// Warn-level items. See [`ItemClass`](enum.ItemClass.html) documentation for details.
@@ -91,14 +108,57 @@ pub enum ItemW<'a> {
     /// Unknown short option `char` [WARN]
     UnknownShort(usize, char),
     /// Looked like a long option, but a name was not actually specified. This only occurs for
-    /// arguments starting with `--=` (in standard mode, `-=` in alternate mode). Because the first
-    /// `=` in a long option argument is interpreted as indication that any subsequent characters
-    /// are a data sub-argument, an `=` immediately following the long option prefix thus gives an
-    /// empty option name. The data (if any) is ignored. [WARN]
+    /// arguments starting with `--=` (in standard mode, `-=` in alternate mode, `/=` or `/:` in DOS
+    /// mode). Because the first `=` in a long option argument is interpreted as indication that
+    /// any subsequent characters are a data sub-argument, an `=` immediately following the long
+    /// option prefix thus gives an empty option name. The data (if any) is ignored. [WARN]
     LongWithNoName(usize),
     /// Long option match, but came with unexpected data. For example `--foo=bar` when `--foo` takes
     /// no data. [WARN]
     LongWithUnexpectedData{ i: usize, n: &'a str, d: &'a str },
+    /// Option argument given after a *non-option*. This only occurs with the
+    /// [`WarnLateOptions`](../options/enum.OrderingPolicy.html#variant.WarnLateOptions) ordering
+    /// policy, and precedes the item(s) describing the option argument itself. [WARN]
+    LateOption(usize),
+    /// Long option match, in negated form (e.g. `++foo`), but the option is not negatable. This
+    /// includes options that expect data. [WARN]
+    LongNotNegatable(usize, &'a str),
+    /// Short option match, in negated form (e.g. `+x`), but the option is not negatable. This
+    /// includes options that expect data. [WARN]
+    ShortNotNegatable(usize, char),
+    /// Long option match, but the option is deprecated, with its replacement, if any. This follows
+    /// the item describing the option itself. [WARN]
+    LongDeprecated(usize, &'a str, Option<&'a str>),
+    /// Short option match, but the option is deprecated, with its replacement, if any. This follows
+    /// the item describing the option itself. [WARN]
+    ShortDeprecated(usize, char, Option<&'a str>),
+    /// Long option match, by an abbreviated name. This only occurs with abbreviation use warnings
+    /// enabled in the option set, and follows the item describing the option itself. [WARN]
+    LongAbbreviated(usize, &'a str),
+}
+
+/// Binding of the *non-options* of an [`Analysis`](struct.Analysis.html) to a set of declared
+/// positionals
+///
+/// See [`Analysis::bind_positionals`](struct.Analysis.html#method.bind_positionals).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositionalAnalysis<'a> {
+    /// Set of items describing the binding, in argument order, with any missing positionals last
+    pub items: Vec<PositionalItem<'a>>,
+    /// Quick indication of error level issues (missing or unexpected positionals)
+    pub error: bool,
+}
+
+/// Positional binding items. See [`PositionalAnalysis`](struct.PositionalAnalysis.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionalItem<'a> {
+    /// *Non-option* bound to a declared positional. Holds the argument index, the positional’s name
+    /// and the value.
+    Bound { i: usize, n: &'a str, v: &'a str },
+    /// Positional which did not receive its minimum number of arguments [ERROR]
+    Missing(&'a str),
+    /// *Non-option* beyond those accepted by the declared positionals [ERROR]
+    Unexpected(usize, &'a str),
 }
 
 /// Used to describe where data was located, for options that require data.
@@ -111,6 +171,44 @@ pub enum DataLocation {
     NextArg,
 }
 
+/// Location of an item within the arguments
+///
+/// See [`Analysis::item_spans`](struct.Analysis.html#method.item_spans).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemSpan<'a> {
+    /// Index of the argument in which the item was found
+    pub arg: usize,
+    /// Byte range within the argument of the item itself, excluding any prefix, polarity suffix
+    /// and *data value*.
+    /// For *long options* this covers the name as typed (thus possibly abbreviated), for *short
+    /// options* the `char`, and for other items the entire argument. (Empty for a *long option*
+    /// without a name).
+    pub range: Range<usize>,
+    /// The text covered by `range`, i.e. what the user actually typed
+    pub text: &'a str,
+    /// Location of the *data value*, where applicable, as argument index and byte range
+    pub data: Option<(usize, Range<usize>)>,
+}
+
+/// The role played by an argument
+///
+/// See [`Analysis::arg_roles`](struct.Analysis.html#method.arg_roles).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgRole {
+    /// Holds one or more options (possibly with an in-same-argument *data value*), including
+    /// problem ones
+    Option,
+    /// *Data value* consumed by the item at the given index (in the analysis item list)
+    Data(usize),
+    /// *Non-option*
+    NonOption,
+    /// *Early terminator*
+    Terminator,
+    /// Holds only legacy options (possibly with their *data value*), or is the *data value* of one,
+    /// these being ignored (thus not described by any item)
+    Ignored,
+}
+
 impl<'a> Analysis<'a> {
     /// Create a new result set (mostly only useful internally)
     pub fn new(size_guess: usize) -> Self {
@@ -125,4 +223,357 @@ impl<'a> Analysis<'a> {
     pub fn add(&mut self, item: ItemClass<'a>) {
         self.items.push(item);
     }
+
+    /// Binds the *non-options* found to the given positionals (typically those of the option set
+    /// used in processing)
+    ///
+    /// Positionals are filled in order of declaration. Each first receives its minimum number of
+    /// arguments, then any arguments left over are handed out in order, up to each positional’s
+    /// maximum. Positionals that cannot be satisfied are reported as missing, and arguments that
+    /// cannot be bound are reported as unexpected. (Thus if no positionals are declared, every
+    /// *non-option* is unexpected).
+    ///
+    /// Expects the positionals to be valid (see the option set `is_valid` methods).
+    pub fn bind_positionals(&self, positionals: &[Positional<'a>]) -> PositionalAnalysis<'a> {
+        let mut results = PositionalAnalysis { items: Vec::new(), error: false };
+
+        let values: Vec<(usize, &'a str)> = self.items.iter().filter_map(|item| match *item {
+            ItemClass::Ok(Item::NonOption(i, v)) => Some((i, v)),
+            _ => None,
+        }).collect();
+
+        let required: usize = positionals.iter().map(|p| p.min).sum();
+        let mut spare = values.len().saturating_sub(required);
+        let mut values = values.into_iter();
+
+        let mut missing = Vec::new();
+        for positional in positionals {
+            let extra = match positional.max {
+                Some(max) => ::std::cmp::min(spare, max.saturating_sub(positional.min)),
+                None => spare,
+            };
+            spare -= extra;
+            let mut bound = 0;
+            while bound < positional.min + extra {
+                match values.next() {
+                    Some((i, v)) => {
+                        results.items.push(PositionalItem::Bound { i, n: positional.name, v });
+                        bound += 1;
+                    },
+                    None => { break; },
+                }
+            }
+            if bound < positional.min {
+                missing.push(PositionalItem::Missing(positional.name));
+            }
+        }
+
+        for (i, v) in values {
+            results.items.push(PositionalItem::Unexpected(i, v));
+            results.error = true;
+        }
+        if !missing.is_empty() {
+            results.items.append(&mut missing);
+            results.error = true;
+        }
+        results
+    }
+
+    /// Gives the leftover arguments, those passed through along with the *non-options*, in order
+    ///
+    /// This is intended for wrapper programs, which consume some options and forward everything
+    /// else on to a child process, with pass-through enabled in the option set. Note that the
+    /// *early terminator* is not included (it is taken to be consumed by the wrapper, as with
+    /// `cargo run -- <args>`), and that any data given to an unknown option in the next argument
+    /// is included by virtue of being a *non-option*.
+    pub fn leftover_args(&self) -> Vec<&'a str> {
+        self.items.iter().filter_map(|item| match *item {
+            ItemClass::Ok(Item::NonOption(_, s)) |
+            ItemClass::Ok(Item::PassThrough(_, s)) => Some(s),
+            _ => None,
+        }).collect()
+    }
+
+    /// Rebuilds an equivalent argument list in canonical form
+    ///
+    /// This is useful for logging or forwarding normalised command lines. The `mode` should be
+    /// that used in processing, and gives the prefixes to use. In the output:
+    ///
+    ///  - Abbreviated *long options* are expanded to their full names (with any name normalization
+    ///    used in matching thus also undone), and abbreviated possible values likewise.
+    ///  - *Short option* sets are split, giving one argument per *short option*.
+    ///  - *Data values* are given in the same argument where `form` is
+    ///    [`SameArg`](enum.DataLocation.html#variant.SameArg) (e.g. `--name=value`, `-ovalue`),
+    ///    otherwise in the next argument. (An empty *short option* *data value* can only be given in
+    ///    the next argument).
+    ///  - The *early terminator* and all *non-options* are preserved, in position.
+    ///
+    /// Problem items are reproduced such that they remain so, for instance an unknown option is
+    /// kept as given, and unexpected data is kept with its option. Note though that these are not
+    /// reproduced exactly, e.g. the data value given with a nameless *long option* is dropped.
+    ///
+    /// Processing the resulting arguments against the same option set gives an equivalent analysis,
+    /// differing only in argument indexes and *data value* locations.
+    pub fn canonical_args(&self, mode: OptionsMode, form: DataLocation) -> Vec<String> {
+        let syntax = mode.syntax();
+        let long_prefix = syntax.long_prefixes.first().cloned().unwrap_or("");
+        let short_prefix = syntax.short_prefix.unwrap_or("");
+        let separator = syntax.data_separators.first().cloned().unwrap_or('=');
+        let long = |name: &str| format!("{}{}", long_prefix, name);
+        let short = |ch: char| format!("{}{}", short_prefix, ch);
+        let negated_long = |name: &str| match syntax.negated_long_prefix {
+            Some(prefix) => format!("{}{}", prefix, name),
+            None => format!("{}{}-", long_prefix, name),
+        };
+        let negated_short = |ch: char| {
+            format!("{}{}", syntax.negated_short_prefix.unwrap_or(short_prefix), ch)
+        };
+
+        let mut args = Vec::with_capacity(self.items.len());
+        // Argument index of the last *short option* written without data, for joining on chars
+        // that cannot stand alone
+        let mut last_short_flag: Option<usize> = None;
+        for item in &self.items {
+            match *item {
+                ItemClass::Ok(Item::NonOption(_, s)) |
+                ItemClass::Ok(Item::PassThrough(_, s)) => { args.push(s.to_string()); },
+                ItemClass::Ok(Item::EarlyTerminator(_)) => {
+                    args.push(syntax.early_terminator.unwrap_or("").to_string());
+                },
+                ItemClass::Ok(Item::Long(_, n)) |
+                ItemClass::Warn(ItemW::UnknownLong(_, n)) |
+                ItemClass::Err(ItemE::AmbiguousLong(_, n)) |
+                ItemClass::Err(ItemE::LongMissingData(_, n)) => { args.push(long(n)); },
+                ItemClass::Ok(Item::LongNegated(_, n)) |
+                ItemClass::Warn(ItemW::LongNotNegatable(_, n)) => { args.push(negated_long(n)); },
+                ItemClass::Ok(Item::LongWithData { n, v: d, .. }) |
+                ItemClass::Err(ItemE::LongInvalidData { n, d, .. }) => match form {
+                    DataLocation::SameArg => {
+                        args.push(format!("{}{}{}", long(n), separator, d));
+                    },
+                    DataLocation::NextArg => {
+                        args.push(long(n));
+                        args.push(d.to_string());
+                    },
+                },
+                ItemClass::Warn(ItemW::LongWithUnexpectedData { n, d, .. }) => {
+                    args.push(format!("{}{}{}", long(n), separator, d));
+                },
+                ItemClass::Warn(ItemW::LongWithNoName(_)) => {
+                    args.push(format!("{}{}", long_prefix, separator));
+                },
+                ItemClass::Ok(Item::Short(i, c)) => {
+                    args.push(short(c));
+                    last_short_flag = Some(i);
+                    continue;
+                },
+                ItemClass::Warn(ItemW::UnknownShort(i, c)) => {
+                    // A lone char like `-` may give an argument of a different type (e.g. `--`)
+                    let arg = short(c);
+                    let stands_alone = Some(&*arg) != syntax.early_terminator
+                        && !syntax.long_prefixes.iter().any(|p| arg.starts_with(p));
+                    match (stands_alone, last_short_flag, args.last_mut()) {
+                        (false, Some(last_i), Some(last)) if last_i == i => { last.push(c); },
+                        _ => { args.push(arg); },
+                    }
+                    last_short_flag = Some(i);
+                    continue;
+                },
+                ItemClass::Ok(Item::ShortNegated(_, c)) |
+                ItemClass::Warn(ItemW::ShortNotNegatable(_, c)) => { args.push(negated_short(c)); },
+                ItemClass::Ok(Item::ShortWithData { c, d, .. }) => match form {
+                    DataLocation::SameArg if !d.is_empty() => {
+                        args.push(format!("{}{}", short(c), d));
+                    },
+                    _ => {
+                        args.push(short(c));
+                        args.push(d.to_string());
+                    },
+                },
+                ItemClass::Err(ItemE::ShortMissingData(_, c)) => { args.push(short(c)); },
+                ItemClass::Warn(ItemW::LateOption(_)) => {},
+                ItemClass::Warn(ItemW::LongDeprecated(..)) |
+                ItemClass::Warn(ItemW::ShortDeprecated(..)) |
+                ItemClass::Warn(ItemW::LongAbbreviated(..)) => { continue; },
+            }
+            last_short_flag = None;
+        }
+        args
+    }
+
+    /// Gives the location of each item within the arguments, in the same order as the items
+    ///
+    /// This provides precise information for tooling, such as which `char` of a *short option* set
+    /// was unknown, where a *data value* starts, or what abbreviated text was typed for a matched
+    /// *long option*. The arguments and `mode` must be those used in processing.
+    pub fn item_spans<A>(&self, args: &'a [A], mode: OptionsMode) -> Vec<ItemSpan<'a>>
+        where A: 'a + AsRef<str>
+    {
+        let syntax = mode.syntax();
+        let mut spans = Vec::with_capacity(self.items.len());
+        // Position after the last *short option* found, as argument index and byte position
+        let mut short_cursor = (None, 0);
+        for item in &self.items {
+            let (arg, data_location) = match *item {
+                ItemClass::Ok(Item::NonOption(i, _)) |
+                ItemClass::Ok(Item::EarlyTerminator(i)) |
+                ItemClass::Ok(Item::PassThrough(i, _)) |
+                ItemClass::Warn(ItemW::LateOption(i)) => {
+                    let arg = args[i].as_ref();
+                    spans.push(ItemSpan { arg: i, range: 0..arg.len(), text: arg, data: None });
+                    continue;
+                },
+                // The same location as the option item preceding it
+                ItemClass::Warn(ItemW::LongDeprecated(..)) |
+                ItemClass::Warn(ItemW::ShortDeprecated(..)) |
+                ItemClass::Warn(ItemW::LongAbbreviated(..)) => {
+                    if let Some(option) = spans.last().cloned() {
+                        spans.push(ItemSpan { data: None, ..option });
+                    }
+                    continue;
+                },
+                ItemClass::Ok(Item::Long(i, _)) |
+                ItemClass::Ok(Item::LongNegated(i, _)) |
+                ItemClass::Err(ItemE::LongMissingData(i, _)) |
+                ItemClass::Err(ItemE::AmbiguousLong(i, _)) |
+                ItemClass::Warn(ItemW::UnknownLong(i, _)) |
+                ItemClass::Warn(ItemW::LongWithNoName(i)) |
+                ItemClass::Warn(ItemW::LongNotNegatable(i, _)) => (i, None),
+                ItemClass::Warn(ItemW::LongWithUnexpectedData { i, .. }) => {
+                    (i, Some(DataLocation::SameArg))
+                },
+                ItemClass::Ok(Item::LongWithData { i, l, .. }) |
+                ItemClass::Err(ItemE::LongInvalidData { i, l, .. }) => (i, Some(l)),
+                ItemClass::Ok(Item::Short(i, c)) |
+                ItemClass::Ok(Item::ShortNegated(i, c)) |
+                ItemClass::Err(ItemE::ShortMissingData(i, c)) |
+                ItemClass::Warn(ItemW::UnknownShort(i, c)) |
+                ItemClass::Warn(ItemW::ShortNotNegatable(i, c)) |
+                ItemClass::Ok(Item::ShortWithData { i, c, .. }) => {
+                    let arg = args[i].as_ref();
+                    if short_cursor.0 != Some(i) {
+                        short_cursor = (Some(i), short_prefix_len(syntax, arg));
+                    }
+                    let start = arg[short_cursor.1..].find(c)
+                        .map_or(arg.len(), |p| short_cursor.1 + p);
+                    let end = start + c.len_utf8();
+                    short_cursor.1 = end;
+                    let data = match *item {
+                        ItemClass::Ok(Item::ShortWithData { l: DataLocation::SameArg, .. }) => {
+                            Some((i, end..arg.len()))
+                        },
+                        ItemClass::Ok(Item::ShortWithData { l: DataLocation::NextArg, .. }) => {
+                            Some((i + 1, 0..args[i + 1].as_ref().len()))
+                        },
+                        _ => None,
+                    };
+                    let text = &arg[start..end];
+                    spans.push(ItemSpan { arg: i, range: start..end, text, data });
+                    continue;
+                },
+            };
+            // Long options
+            let arg_str = args[arg].as_ref();
+            let start = long_prefix_len(syntax, arg_str);
+            let separator = arg_str[start..].char_indices()
+                .find(|&(_, c)| syntax.data_separators.contains(&c))
+                .map(|(p, c)| (start + p, c.len_utf8()));
+            let mut end = separator.map_or(arg_str.len(), |(p, _)| p);
+            // Excluding any polarity suffix (e.g. `/foo-`)
+            if syntax.polarity_suffixes {
+                let suffix = match *item {
+                    ItemClass::Ok(Item::Long(_, n)) if !n.ends_with('+') => Some('+'),
+                    ItemClass::Ok(Item::LongNegated(_, n)) if !n.ends_with('-') => Some('-'),
+                    _ => None,
+                };
+                if let Some(suffix) = suffix.filter(|&c| arg_str[start..end].ends_with(c)) {
+                    end -= suffix.len_utf8();
+                }
+            }
+            let data = match data_location {
+                Some(DataLocation::SameArg) => {
+                    separator.map(|(p, len)| (arg, (p + len)..arg_str.len()))
+                },
+                Some(DataLocation::NextArg) => Some((arg + 1, 0..args[arg + 1].as_ref().len())),
+                None => None,
+            };
+            spans.push(ItemSpan { arg, range: start..end, text: &arg_str[start..end], data });
+        }
+        spans
+    }
+
+    /// Gives the role played by each argument
+    ///
+    /// The `arg_count` must be the number of arguments processed.
+    pub fn arg_roles(&self, arg_count: usize) -> Vec<ArgRole> {
+        let mut roles = Vec::with_capacity(arg_count);
+        for (index, item) in self.items.iter().enumerate() {
+            let (arg, role) = match *item {
+                ItemClass::Ok(Item::NonOption(i, _)) => (i, ArgRole::NonOption),
+                ItemClass::Ok(Item::EarlyTerminator(i)) => (i, ArgRole::Terminator),
+                ItemClass::Ok(Item::LongWithData { i, l: DataLocation::NextArg, .. }) |
+                ItemClass::Err(ItemE::LongInvalidData { i, l: DataLocation::NextArg, .. }) |
+                ItemClass::Ok(Item::ShortWithData { i, l: DataLocation::NextArg, .. }) => {
+                    while roles.len() < i {
+                        roles.push(ArgRole::Ignored);
+                    }
+                    if roles.len() == i {
+                        roles.push(ArgRole::Option);
+                    }
+                    (i + 1, ArgRole::Data(index))
+                },
+                ItemClass::Ok(Item::Long(i, _)) |
+                ItemClass::Ok(Item::LongNegated(i, _)) |
+                ItemClass::Ok(Item::LongWithData { i, .. }) |
+                ItemClass::Err(ItemE::LongInvalidData { i, .. }) |
+                ItemClass::Ok(Item::Short(i, _)) |
+                ItemClass::Ok(Item::ShortNegated(i, _)) |
+                ItemClass::Ok(Item::ShortWithData { i, .. }) |
+                ItemClass::Ok(Item::PassThrough(i, _)) |
+                ItemClass::Err(ItemE::LongMissingData(i, _)) |
+                ItemClass::Err(ItemE::ShortMissingData(i, _)) |
+                ItemClass::Err(ItemE::AmbiguousLong(i, _)) |
+                ItemClass::Warn(ItemW::UnknownLong(i, _)) |
+                ItemClass::Warn(ItemW::UnknownShort(i, _)) |
+                ItemClass::Warn(ItemW::LongWithNoName(i)) |
+                ItemClass::Warn(ItemW::LongWithUnexpectedData { i, .. }) |
+                ItemClass::Warn(ItemW::LateOption(i)) |
+                ItemClass::Warn(ItemW::LongNotNegatable(i, _)) |
+                ItemClass::Warn(ItemW::ShortNotNegatable(i, _)) |
+                ItemClass::Warn(ItemW::LongDeprecated(i, ..)) |
+                ItemClass::Warn(ItemW::ShortDeprecated(i, ..)) |
+                ItemClass::Warn(ItemW::LongAbbreviated(i, _)) => (i, ArgRole::Option),
+            };
+            // Arguments not described by any item were consumed by legacy options
+            while roles.len() < arg {
+                roles.push(ArgRole::Ignored);
+            }
+            if roles.len() == arg {
+                roles.push(role);
+            }
+        }
+        roles.resize(arg_count, ArgRole::Ignored);
+        roles
+    }
+}
+
+/// Gives the length of the prefix of a *long option* argument, per the syntax
+///
+/// This also covers *short option* prefixed arguments taken as *long options* (long-only mode),
+/// and negated ones.
+fn long_prefix_len(syntax: &Syntax, arg: &str) -> usize {
+    let long_first_prefix = syntax.short_prefix.filter(|_| syntax.long_first);
+    syntax.long_prefixes.iter().cloned()
+        .chain(long_first_prefix)
+        .chain(syntax.negated_long_prefix)
+        .find(|prefix| arg.starts_with(prefix))
+        .map_or(0, str::len)
+}
+
+/// Gives the length of the prefix of a *short option* set argument, per the syntax
+fn short_prefix_len(syntax: &Syntax, arg: &str) -> usize {
+    syntax.short_prefix.into_iter()
+        .chain(syntax.negated_short_prefix)
+        .find(|prefix| arg.starts_with(prefix))
+        .map_or(0, str::len)
 }
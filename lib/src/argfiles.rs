@@ -0,0 +1,323 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Response file (`@file`) argument expansion
+//!
+//! This is an optional pre-processing step, to be performed on an argument list before
+//! processing, which replaces any `@path` arguments with the arguments held within the file at
+//! `path`. This is useful for getting around argument list length limits.
+//!
+//! File content is split into arguments with shell-like rules:
+//!
+//!  - Arguments are separated by whitespace (including line breaks).
+//!  - Single quotes (`'`) preserve everything up to the closing quote literally.
+//!  - Double quotes (`"`) similarly preserve content, except that a backslash can be used to escape
+//!    a double quote or backslash within them.
+//!  - Outside of quotes, a backslash escapes the next character (a line break thus escaped is a
+//!    line continuation).
+//!  - A hash (`#`) at the start of an argument begins a comment, which runs to the end of the line.
+//!
+//! Response files may themselves contain `@path` arguments, which are expanded in turn, subject to
+//! a depth limit and a cycle check. Relative paths are always resolved against the current working
+//! directory (as with the GCC compiler). An `@` on its own is not taken as a file reference.
+//!
+//! Arguments in the expansion are recorded along with where they came from, allowing the indices
+//! held in an [`Analysis`] of the expanded list to be traced back for error messages.
+//!
+//! [`Analysis`]: ../analysis/struct.Analysis.html
+
+use std::convert::AsRef;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Default limit of response file nesting depth
+pub const DEFAULT_MAX_DEPTH: usize = 16;
+
+/// Prefix identifying a response file argument
+const FILE_PREFIX: char = '@';
+
+/// Where an argument of an expansion came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgOrigin {
+    /// From the original argument list, at the given index
+    CommandLine(usize),
+    /// From a response file, given as an index into the expansion’s `files` list, along with the
+    /// (one-based) line number on which the argument starts
+    File { file: usize, line: usize },
+}
+
+/// The result of expanding an argument list
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Expansion {
+    /// The expanded argument list
+    pub args: Vec<String>,
+    /// The origin of each argument in `args`
+    pub origins: Vec<ArgOrigin>,
+    /// The response files read, in the order first read
+    pub files: Vec<PathBuf>,
+}
+
+/// Response file expansion failure
+#[derive(Debug)]
+pub enum ExpandError {
+    /// A file could not be read
+    Io { path: PathBuf, error: io::Error },
+    /// A file has an unterminated quote, starting on the given (one-based) line
+    UnterminatedQuote { path: PathBuf, line: usize },
+    /// The nesting depth limit was exceeded in trying to read the file
+    TooDeep { path: PathBuf },
+    /// The file (indirectly) references itself
+    Cycle { path: PathBuf },
+}
+
+impl fmt::Display for ExpandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ExpandError::Io { ref path, ref error } =>
+                write!(f, "failed to read response file `{}`: {}", path.display(), error),
+            ExpandError::UnterminatedQuote { ref path, line } => write!(f,
+                "unterminated quote in response file `{}`, line {}", path.display(), line),
+            ExpandError::TooDeep { ref path } =>
+                write!(f, "response file nesting too deep at `{}`", path.display()),
+            ExpandError::Cycle { ref path } =>
+                write!(f, "response file `{}` references itself", path.display()),
+        }
+    }
+}
+
+impl Error for ExpandError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ExpandError::Io { ref error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Expansion {
+    /// Describes where the argument at the given index came from, e.g. for use in error messages
+    ///
+    /// Returns `None` if the index is out of range.
+    pub fn describe_origin(&self, index: usize) -> Option<String> {
+        self.origins.get(index).map(|origin| match *origin {
+            ArgOrigin::CommandLine(i) => format!("argument {}", i),
+            ArgOrigin::File { file, line } =>
+                format!("{}:{}", self.files[file].display(), line),
+        })
+    }
+}
+
+/// Expands response file arguments, using the default depth limit
+///
+/// See the [module documentation](index.html) for details.
+pub fn expand<A>(args: &[A]) -> Result<Expansion, ExpandError>
+    where A: AsRef<str>
+{
+    expand_with_limit(args, DEFAULT_MAX_DEPTH)
+}
+
+/// Expands response file arguments, with a custom nesting depth limit
+///
+/// A `max_depth` of one allows response files, but not nested ones; zero disallows reading any.
+pub fn expand_with_limit<A>(args: &[A], max_depth: usize) -> Result<Expansion, ExpandError>
+    where A: AsRef<str>
+{
+    let mut expansion = Expansion {
+        args: Vec::with_capacity(args.len()),
+        origins: Vec::with_capacity(args.len()),
+        files: Vec::new(),
+    };
+    let mut chain = Vec::new();
+    for (i, arg) in args.iter().enumerate() {
+        let arg = arg.as_ref();
+        match file_reference(arg) {
+            Some(path) => {
+                expand_file(&mut expansion, &mut chain, Path::new(path), max_depth)?;
+            },
+            None => {
+                expansion.args.push(String::from(arg));
+                expansion.origins.push(ArgOrigin::CommandLine(i));
+            },
+        }
+    }
+    Ok(expansion)
+}
+
+/// Gives the path of a response file argument, if it is one
+fn file_reference(arg: &str) -> Option<&str> {
+    match arg.starts_with(FILE_PREFIX) && arg.len() > FILE_PREFIX.len_utf8() {
+        true => Some(&arg[FILE_PREFIX.len_utf8()..]),
+        false => None,
+    }
+}
+
+/// Reads and expands a response file into `expansion`
+///
+/// `chain` holds the (canonical) paths of the files currently being expanded, for cycle detection.
+fn expand_file(expansion: &mut Expansion, chain: &mut Vec<PathBuf>, path: &Path,
+    max_depth: usize) -> Result<(), ExpandError>
+{
+    if chain.len() >= max_depth {
+        return Err(ExpandError::TooDeep { path: path.to_path_buf() });
+    }
+    let io_err = |error| ExpandError::Io { path: path.to_path_buf(), error };
+    let canonical = fs::canonicalize(path).map_err(&io_err)?;
+    if chain.contains(&canonical) {
+        return Err(ExpandError::Cycle { path: path.to_path_buf() });
+    }
+    let text = fs::read_to_string(path).map_err(&io_err)?;
+    let tokens = split(&text).map_err(|line| {
+        ExpandError::UnterminatedQuote { path: path.to_path_buf(), line }
+    })?;
+
+    let file = match expansion.files.iter().position(|f| f == path) {
+        Some(file) => file,
+        None => { expansion.files.push(path.to_path_buf()); expansion.files.len() - 1 },
+    };
+    chain.push(canonical);
+    for (arg, line) in tokens {
+        match file_reference(&arg) {
+            Some(nested) => {
+                expand_file(expansion, chain, Path::new(nested), max_depth)?;
+            },
+            None => {
+                expansion.args.push(arg);
+                expansion.origins.push(ArgOrigin::File { file, line });
+            },
+        }
+    }
+    chain.pop();
+    Ok(())
+}
+
+/// Splits response file content into arguments, with shell-like quoting and comments
+///
+/// Each argument is returned along with the (one-based) line number it starts on. On failure, the
+/// line number of an unterminated quote is returned.
+fn split(text: &str) -> Result<Vec<(String, usize)>, usize> {
+    let mut args = Vec::new();
+    let mut current: Option<(String, usize)> = None;
+    let mut line = 1;
+    let mut chars = text.chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\n' | ' ' | '\t' | '\r' => {
+                if let Some(arg) = current.take() {
+                    args.push(arg);
+                }
+                if ch == '\n' {
+                    line += 1;
+                }
+            },
+            '#' if current.is_none() => {
+                for ch in chars.by_ref() {
+                    if ch == '\n' {
+                        line += 1;
+                        break;
+                    }
+                }
+            },
+            '\'' | '"' => {
+                let start_line = line;
+                let arg = &mut current.get_or_insert_with(|| (String::new(), line)).0;
+                let mut terminated = false;
+                while let Some(quoted) = chars.next() {
+                    match quoted {
+                        _ if quoted == ch => { terminated = true; break; },
+                        '\\' if ch == '"' => {
+                            match chars.next() {
+                                Some(escaped @ '"') | Some(escaped @ '\\') => arg.push(escaped),
+                                Some(other) => {
+                                    if other == '\n' {
+                                        line += 1;
+                                    }
+                                    arg.push('\\');
+                                    arg.push(other);
+                                },
+                                None => { break; },
+                            }
+                        },
+                        _ => {
+                            if quoted == '\n' {
+                                line += 1;
+                            }
+                            arg.push(quoted);
+                        },
+                    }
+                }
+                if !terminated {
+                    return Err(start_line);
+                }
+            },
+            '\\' => {
+                match chars.next() {
+                    // Line continuation
+                    Some('\n') => { line += 1; },
+                    Some(escaped) => {
+                        current.get_or_insert_with(|| (String::new(), line)).0.push(escaped);
+                    },
+                    None => {},
+                }
+            },
+            _ => {
+                current.get_or_insert_with(|| (String::new(), line)).0.push(ch);
+            },
+        }
+    }
+    if let Some(arg) = current.take() {
+        args.push(arg);
+    }
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split;
+
+    fn args(text: &str) -> Vec<String> {
+        split(text).unwrap().into_iter().map(|(arg, _)| arg).collect()
+    }
+
+    #[test]
+    fn splitting() {
+        assert_eq!(args("  -a  --foo=bar\n\tbaz "), vec!["-a", "--foo=bar", "baz"]);
+        assert_eq!(args("'a b' \"c d\" e\\ f"), vec!["a b", "c d", "e f"]);
+        assert_eq!(args("pre'fix'\"ed\" ''"), vec!["prefixed", ""]);
+        assert_eq!(args(r#""q\"uo\\te" "\n" 'no\escape'"#),
+            vec![r#"q"uo\te"#, r"\n", r"no\escape"]);
+        assert_eq!(args("a\\\nb c"), vec!["ab", "c"]);
+    }
+
+    #[test]
+    fn comments() {
+        assert_eq!(args("# comment\n-a # trailing\nb#not-comment"), vec!["-a", "b#not-comment"]);
+        assert_eq!(args("'#quoted'"), vec!["#quoted"]);
+    }
+
+    #[test]
+    fn line_numbers() {
+        assert_eq!(split("a\n\n'b\nc' d\n#x\ne"), Ok(vec![
+            (String::from("a"), 1),
+            (String::from("b\nc"), 3),
+            (String::from("d"), 4),
+            (String::from("e"), 6),
+        ]));
+    }
+
+    #[test]
+    fn unterminated() {
+        assert_eq!(split("a\n'b\nc"), Err(2));
+        assert_eq!(split("\"abc\\\""), Err(1));
+    }
+}
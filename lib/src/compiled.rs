@@ -0,0 +1,150 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Compiled option sets
+//!
+//! Processing with a plain option set involves a linear search of the option lists for every
+//! *long option* argument and every *short option* character, which is perfectly fine for typical
+//! programs, but becomes costly with very large sets (thousands of options). A
+//! [`CompiledOptionSet`] is built once from an option set, indexing its options for fast lookup,
+//! and gives exactly the same analysis results.
+//!
+//! [`CompiledOptionSet`]: struct.CompiledOptionSet.html
+
+use super::analysis::Analysis;
+use super::engine::{self, LongMatch, Lookup};
+use super::options::*;
+
+/// Number of entries in the direct lookup table for *short options*
+const ASCII_COUNT: usize = 128;
+
+/// An option set indexed for fast lookup
+///
+/// *Long options* are held sorted by (normalized) name, such that both exact and abbreviated
+/// matches are found with a binary search; *short options* using ASCII characters are found with
+/// a direct table lookup, and others with a binary search.
+///
+/// This is `Send` and `Sync`, thus can be built once and shared between threads.
+///
+/// Note that the option set is copied, thus any subsequent changes to an extendible option set it
+/// was built from are not reflected; re-compile after making changes.
+#[derive(Debug, Clone)]
+pub struct CompiledOptionSet<'r, 'a: 'r> {
+    /// The option set
+    set: OptionSet<'r, 'a>,
+    /// Normalized *long option* names, with option index, sorted by name then index
+    long: Vec<(String, usize)>,
+    /// Index of *short options* by ASCII character (first declared only)
+    short_ascii: [Option<usize>; ASCII_COUNT],
+    /// Non-ASCII *short options*, with option index, sorted by character then index
+    short_other: Vec<(char, usize)>,
+}
+
+impl<'r, 'a: 'r> CompiledOptionSet<'r, 'a> {
+    /// Builds an indexed form of the given option set
+    ///
+    /// Expects `set` to be valid (see [`OptionSet::is_valid`]).
+    ///
+    /// [`OptionSet::is_valid`]: ../options/struct.OptionSet.html#method.is_valid
+    pub fn new(set: &OptionSet<'r, 'a>) -> Self {
+        // Note, this must account for the mode, as done in processing
+        let normalization = set.name_normalization();
+        let mut long: Vec<(String, usize)> = set.long.iter()
+            .enumerate()
+            .map(|(i, candidate)| (normalization.apply(candidate.name).into_owned(), i))
+            .collect();
+        long.sort();
+
+        let mut short_ascii = [None; ASCII_COUNT];
+        let mut short_other = Vec::new();
+        for (i, candidate) in set.short.iter().enumerate() {
+            match candidate.ch.is_ascii() {
+                true => {
+                    let slot = &mut short_ascii[candidate.ch as usize];
+                    if slot.is_none() {
+                        *slot = Some(i);
+                    }
+                },
+                false => short_other.push((candidate.ch, i)),
+            }
+        }
+        short_other.sort();
+
+        Self { set: set.clone(), long, short_ascii, short_other }
+    }
+
+    /// The option set compiled
+    #[inline(always)]
+    pub fn options(&self) -> &OptionSet<'r, 'a> {
+        &self.set
+    }
+
+    /// Analyses provided program arguments
+    ///
+    /// This gives exactly the same result as [`OptionSet::process`] with the original set.
+    ///
+    /// [`OptionSet::process`]: ../options/struct.OptionSet.html#method.process
+    pub fn process<T>(&self, args: &'a [T]) -> Analysis<'a>
+        where T: AsRef<str>
+    {
+        engine::process(args, self)
+    }
+}
+
+impl<'r, 'a: 'r> Lookup<'r, 'a> for CompiledOptionSet<'r, 'a> {
+    fn options(&self) -> &OptionSet<'r, 'a> {
+        &self.set
+    }
+
+    fn find_long(&self, normalization: &Normalization, name: &str) -> LongMatch<'r, 'a> {
+        let long: &'r [LongOption<'a>] = self.set.long;
+        let name = normalization.apply(name);
+
+        // Entries starting with `name` are contiguous from here, with any exact match first, and
+        // with duplicates in declaration order.
+        let start = self.long.partition_point(|(candidate_name, _)| **candidate_name < *name);
+        let entries = self.long[start..].iter()
+            .take_while(|(candidate_name, _)| candidate_name.starts_with(&*name));
+
+        let mut matched: Option<&LongOption> = None;
+        for (candidate_name, index) in entries {
+            let candidate = &long[*index];
+            // Exact
+            if **candidate_name == *name {
+                return LongMatch::Found(candidate);
+            }
+            // Abbreviated
+            if self.set.allow_abbreviations && candidate.allow_abbreviation
+                && name.chars().count() >= candidate.min_abbreviation
+            {
+                match matched {
+                    Some(_) => { return LongMatch::Ambiguous; },
+                    None => { matched = Some(candidate); },
+                }
+            }
+        }
+        match matched {
+            Some(matched) => LongMatch::Found(matched),
+            None => LongMatch::None,
+        }
+    }
+
+    fn find_short(&self, ch: char) -> Option<&'r ShortOption> {
+        let short: &'r [ShortOption] = self.set.short;
+        if ch.is_ascii() {
+            return self.short_ascii[ch as usize].map(|i| &short[i]);
+        }
+        let start = self.short_other.partition_point(|&(candidate, _)| candidate < ch);
+        match self.short_other.get(start) {
+            Some(&(candidate, i)) if candidate == ch => Some(&short[i]),
+            _ => None,
+        }
+    }
+}
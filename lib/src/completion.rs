@@ -0,0 +1,212 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Runtime completion
+//!
+//! This provides completion of partially typed command lines, for serving dynamic completions to
+//! any shell, for instance from a hidden `--complete` entry point.
+//!
+//! The arguments preceding the one being completed are processed just as with the option set’s
+//! `process` method, thus the same semantics apply in determining what the argument being
+//! completed is. For instance, with abbreviations enabled, the argument after `--ha` is a *data
+//! value* if `--ha` is an unambiguous abbreviation of a data taking option, while with
+//! abbreviations disabled, `--ha` is unknown, and so the argument after it is not a *data value*.
+//!
+//! Options are only offered once a prefix has been typed (thus an empty argument is taken as a
+//! *non-option*), with *long option* candidates matched by prefix just as with abbreviations
+//! (applying the name normalization policy), and always given in full. Hidden and legacy options
+//! are never offered (see `OptionStatus::is_hidden`), though the *data values* of legacy options
+//! are still recognised as such.
+
+use super::analysis::*;
+use super::options::*;
+
+/// What the argument being completed is, with candidates where applicable
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Completion {
+    /// Options, with the possible completions of the argument (full argument text, in declaration
+    /// order, *long options* before *short options*)
+    Options(Vec<String>),
+    /// *Data value* of the named *long option*, with any argument text preceding the value (e.g.
+    /// `--foo=`, empty if the value is the entire argument), and those of the option’s possible
+    /// values that start with the value typed so far (in declaration order)
+    LongData { name: String, prefix: String, values: Vec<String> },
+    /// *Data value* of the *short option*, with any argument text preceding the value (e.g. `-xo`,
+    /// empty if the value is the entire argument)
+    ShortData { ch: char, prefix: String },
+    /// *Non-option* (for instance a positional, for which the program may offer candidates)
+    NonOption,
+}
+
+/// Determines completions for the argument at index `cursor`
+///
+/// The arguments should be the partial command line (excluding the program name), with the
+/// argument being completed containing the text typed so far. A `cursor` equal to (or beyond) the
+/// number of arguments is taken as completing a new, empty, argument.
+///
+/// Expects the option set to be valid (see the option set `is_valid` methods).
+pub fn complete<A>(options: &OptionSet, args: &[A], cursor: usize) -> Completion
+    where A: AsRef<str>
+{
+    let cursor = cursor.min(args.len());
+    let preceding = &args[..cursor];
+    let word = args.get(cursor).map_or("", |a| a.as_ref());
+
+    // Legacy options are ignored in processing, giving no items, but their *data values* must
+    // still be recognised, thus they are processed here as normal options
+    let mut active = options.to_extendible();
+    for option in &mut active.long {
+        if option.status == OptionStatus::Legacy {
+            option.status = OptionStatus::Normal;
+        }
+    }
+    for option in &mut active.short {
+        if option.status == OptionStatus::Legacy {
+            option.status = OptionStatus::Normal;
+        }
+    }
+    let active = active.as_fixed();
+
+    // Warnings (e.g. of deprecation) may follow the item for an option, thus are skipped
+    let analysis = active.process(preceding);
+    match last_option_item(&analysis) {
+        Some(&ItemClass::Err(ItemE::LongMissingData(i, n))) if i + 1 == cursor => {
+            return long_data(options, n, "", word);
+        },
+        Some(&ItemClass::Err(ItemE::ShortMissingData(i, c))) if i + 1 == cursor => {
+            return Completion::ShortData { ch: c, prefix: String::new() };
+        },
+        _ => {},
+    }
+
+    // Processing of options may have ended
+    let mut non_options = 0;
+    for item in &analysis.items {
+        match *item {
+            ItemClass::Ok(Item::EarlyTerminator(_)) => { return Completion::NonOption; },
+            ItemClass::Ok(Item::NonOption(..)) => { non_options += 1; },
+            _ => {},
+        }
+    }
+    if let OrderingPolicy::StopAtNonOption(limit) = options.ordering {
+        if non_options >= limit {
+            return Completion::NonOption;
+        }
+    }
+
+    let word_arg = [word];
+    let word_analysis = active.process(&word_arg);
+    let syntax = options.mode.syntax();
+    let normalization = options.name_normalization();
+    let long_matches = |partial: &str, negated: bool| -> Vec<&str> {
+        let partial = normalization.apply(partial);
+        options.long.iter()
+            .filter(|o| !o.status.is_hidden())
+            .filter(|o| !negated || (o.negatable && !o.expects_data))
+            .filter(|o| normalization.apply(o.name).starts_with(&*partial))
+            .map(|o| o.name)
+            .collect()
+    };
+
+    // Lone prefixes are not options in themselves, but we offer candidates for them
+    let is_lone_prefix = syntax.long_prefixes.contains(&word)
+        || Some(word) == syntax.short_prefix
+        || (options.plus_prefix
+            && (Some(word) == syntax.negated_long_prefix
+                || Some(word) == syntax.negated_short_prefix));
+
+    // Data within the same argument (the last item, for *short options*)
+    match last_option_item(&word_analysis) {
+        Some(&ItemClass::Ok(Item::LongWithData { n, d, l: DataLocation::SameArg, .. })) |
+        Some(&ItemClass::Err(ItemE::LongInvalidData { n, d, l: DataLocation::SameArg, .. })) => {
+            let prefix = &word[..(word.len() - d.len())];
+            return long_data(options, n, prefix, d);
+        },
+        Some(&ItemClass::Ok(Item::ShortWithData { c, d, l: DataLocation::SameArg, .. })) => {
+            let prefix = &word[..(word.len() - d.len())];
+            return Completion::ShortData { ch: c, prefix: prefix.to_string() };
+        },
+        _ => {},
+    }
+
+    match word_analysis.items.first() {
+        Some(&ItemClass::Ok(Item::NonOption(..))) if !is_lone_prefix => Completion::NonOption,
+        None => Completion::NonOption,
+        _ => {
+            let mut candidates = Vec::new();
+            let long_first_prefix = syntax.short_prefix.filter(|_| syntax.long_first);
+            let long_prefix = syntax.long_prefixes.iter().cloned()
+                .chain(long_first_prefix)
+                .find(|p| word.starts_with(p));
+            let negated_long_prefix = syntax.negated_long_prefix
+                .filter(|p| options.plus_prefix && word.starts_with(p));
+            if let Some(prefix) = long_prefix {
+                for name in long_matches(&word[prefix.len()..], false) {
+                    candidates.push(format!("{}{}", prefix, name));
+                }
+            }
+            else if let Some(prefix) = negated_long_prefix {
+                for name in long_matches(&word[prefix.len()..], true) {
+                    candidates.push(format!("{}{}", prefix, name));
+                }
+            }
+            // A lone *short option* prefix lists the *short options*, along with the *long
+            // options* (where their prefix starts with it)
+            if Some(word) == syntax.short_prefix
+                || (options.plus_prefix && Some(word) == syntax.negated_short_prefix)
+            {
+                let negated = Some(word) != syntax.short_prefix;
+                let long_prefix = match negated {
+                    false => syntax.long_prefixes.iter().cloned().find(|p| p.starts_with(word)),
+                    true => syntax.negated_long_prefix.filter(|p| p.starts_with(word)),
+                };
+                // (Already done above, in long-only style)
+                if let (Some(prefix), None) = (long_prefix, long_first_prefix) {
+                    for name in long_matches("", negated) {
+                        candidates.push(format!("{}{}", prefix, name));
+                    }
+                }
+                for option in options.short.iter().filter(|o| !o.status.is_hidden()) {
+                    if !negated || (option.negatable && !option.expects_data) {
+                        candidates.push(format!("{}{}", word, option.ch));
+                    }
+                }
+            }
+            // A valid *short option* set is complete as it is (deprecated options being valid)
+            else if candidates.is_empty() && !word_analysis.error
+                && word_analysis.items.iter().all(|item| match *item {
+                    ItemClass::Warn(ItemW::ShortDeprecated(..)) => true,
+                    ItemClass::Warn(_) => false,
+                    _ => true,
+                })
+            {
+                candidates.push(word.to_string());
+            }
+            Completion::Options(candidates)
+        },
+    }
+}
+
+/// Gives the last item that is not a warning
+fn last_option_item<'r, 'a>(analysis: &'r Analysis<'a>) -> Option<&'r ItemClass<'a>> {
+    analysis.items.iter().rev().find(|item| !matches!(item, ItemClass::Warn(_)))
+}
+
+/// Builds a long option data completion, with the possible values matching the typed `value`
+fn long_data(options: &OptionSet, name: &str, prefix: &str, value: &str) -> Completion {
+    let values = options.long.iter()
+        .find(|o| o.name == name)
+        .map_or(&[][..], |o| o.possible_values)
+        .iter()
+        .filter(|v| v.starts_with(value))
+        .map(|v| v.to_string())
+        .collect();
+    Completion::LongData { name: name.to_string(), prefix: prefix.to_string(), values }
+}
@@ -0,0 +1,203 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Configuration file layer
+//!
+//! Configuration files provide values for *long options*, as an additional layer beneath the
+//! command line, for use with [`OptionValues`]. The file path is up to the caller.
+//!
+//! The file format is a simple TOML/INI-like one, consisting of one entry per line:
+//!
+//!  - `name = value` gives a value for the *long option* called `name` (no prefix, and no
+//!    abbreviation, though the option set’s name normalization policy applies). The value can be
+//!    left bare, in which case surrounding whitespace is removed, or can be quoted, with either
+//!    double (`"`) or single (`'`) quotes, within which content is taken literally (there are no
+//!    escapes). A comment may follow a quoted value.
+//!  - `name` on its own gives a flag type option. For TOML compatibility, flag type options can
+//!    also be given the values `true` (the same) and `false` (explicitly turning the option off,
+//!    reported as a negated match, `LongNegated`, regardless of whether the option is negatable).
+//!  - Blank lines, and lines starting with `#` or `;`, are ignored.
+//!
+//! Sections (`[name]`) are not supported.
+//!
+//! For options with possible values, the value must match one exactly (abbreviations are not
+//! accepted, as with names).
+//!
+//! Entries are analysed against an option set with the same rules as command line arguments,
+//! producing an [`Analysis`], with item indices holding (one-based) line numbers. Thus entries for
+//! unknown options are reported as [`UnknownLong`], values for flag type options as
+//! [`LongWithUnexpectedData`], and so on.
+//!
+//! [`Analysis`]: ../analysis/struct.Analysis.html
+//! [`OptionValues`]: ../values/struct.OptionValues.html
+//! [`UnknownLong`]: ../analysis/enum.ItemW.html#variant.UnknownLong
+//! [`LongWithUnexpectedData`]: ../analysis/enum.ItemW.html#variant.LongWithUnexpectedData
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use super::analysis::*;
+use super::engine::{match_value, note_deprecated_long};
+use super::options::*;
+
+/// A configuration file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigFile {
+    /// File path (used in describing value sources)
+    pub path: PathBuf,
+    /// File content
+    pub text: String,
+}
+
+/// Configuration file syntax error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyntaxError {
+    /// Line number (one-based)
+    pub line: usize,
+    /// What was wrong
+    pub kind: SyntaxErrorKind,
+}
+
+/// Configuration file syntax error type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxErrorKind {
+    /// Section header (these are not supported)
+    Section,
+    /// Quoted value is missing its closing quote
+    UnterminatedQuote,
+    /// Something other than a comment follows a quoted value
+    TrailingCharacters,
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let desc = match self.kind {
+            SyntaxErrorKind::Section => "sections are not supported",
+            SyntaxErrorKind::UnterminatedQuote => "unterminated quote",
+            SyntaxErrorKind::TrailingCharacters => "unexpected characters after quoted value",
+        };
+        write!(f, "line {}: {}", self.line, desc)
+    }
+}
+
+impl Error for SyntaxError {}
+
+impl ConfigFile {
+    /// Create from already obtained content
+    pub fn new<P: Into<PathBuf>>(path: P, text: String) -> Self {
+        Self { path: path.into(), text }
+    }
+
+    /// Load from the given path
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let text = fs::read_to_string(path.as_ref())?;
+        Ok(Self::new(path.as_ref(), text))
+    }
+
+    /// Analyses the entries against the given option set
+    ///
+    /// Item indices in the returned analysis are (one-based) line numbers.
+    pub fn analyse<'a>(&'a self, set: &OptionSet<'_, 'a>) -> Result<Analysis<'a>, SyntaxError> {
+        let mut results = Analysis::new(0);
+        for (line_index, line) in self.text.lines().enumerate() {
+            let line_num = line_index + 1;
+            let entry = match parse_line(line) {
+                Ok(Some(entry)) => entry,
+                Ok(None) => { continue; },
+                Err(kind) => { return Err(SyntaxError { line: line_num, kind }); },
+            };
+            add_entry(&mut results, set, line_num, entry);
+        }
+        Ok(results)
+    }
+}
+
+/// Analyses an entry, adding the resulting item
+fn add_entry<'a>(results: &mut Analysis<'a>, set: &OptionSet<'_, 'a>, line_num: usize,
+    (key, value): (&'a str, Option<&'a str>))
+{
+    if key.is_empty() {
+        results.add(ItemClass::Warn(ItemW::LongWithNoName(line_num)));
+        results.warn = true;
+        return;
+    }
+    let normalization = set.name_normalization();
+    let key_normalized = normalization.apply(key);
+    let option = match set.long.iter().find(|o| normalization.apply(o.name) == key_normalized) {
+        Some(option) => option,
+        None => {
+            results.add(ItemClass::Warn(ItemW::UnknownLong(line_num, key)));
+            results.warn = true;
+            return;
+        },
+    };
+    if option.status == OptionStatus::Legacy {
+        return;
+    }
+    let name = option.name;
+    match (option.expects_data, value) {
+        (true, Some(data)) => match match_value(option, data, false) {
+            Some(value) => {
+                results.add(ItemClass::Ok(Item::LongWithData {
+                    i: line_num, n: name, d: data, v: value, l: DataLocation::SameArg }));
+            },
+            None => {
+                results.add(ItemClass::Err(ItemE::LongInvalidData {
+                    i: line_num, n: name, d: data, l: DataLocation::SameArg,
+                    v: option.possible_values }));
+                results.error = true;
+            },
+        },
+        (true, None) => {
+            results.add(ItemClass::Err(ItemE::LongMissingData(line_num, name)));
+            results.error = true;
+        },
+        (false, None) | (false, Some("true")) => {
+            results.add(ItemClass::Ok(Item::Long(line_num, name)));
+        },
+        (false, Some("false")) => {
+            results.add(ItemClass::Ok(Item::LongNegated(line_num, name)));
+        },
+        (false, Some(data)) => {
+            results.add(ItemClass::Warn(ItemW::LongWithUnexpectedData {
+                i: line_num, n: name, d: data }));
+            results.warn = true;
+        },
+    }
+    note_deprecated_long(results, line_num, option);
+}
+
+/// Parses a line, giving the key and value of an entry, or `None` for blank and comment lines
+fn parse_line(line: &str) -> Result<Option<(&str, Option<&str>)>, SyntaxErrorKind> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+        return Ok(None);
+    }
+    if line.starts_with('[') {
+        return Err(SyntaxErrorKind::Section);
+    }
+    let (key, value) = match line.find('=') {
+        None => { return Ok(Some((line, None))); },
+        Some(i) => (line[..i].trim_end(), line[i + 1..].trim_start()),
+    };
+    let quote = match value.chars().next() {
+        Some(q @ '"') | Some(q @ '\'') => q,
+        _ => { return Ok(Some((key, Some(value)))); },
+    };
+    let quoted = &value[1..];
+    let end = quoted.find(quote).ok_or(SyntaxErrorKind::UnterminatedQuote)?;
+    let rest = quoted[end + 1..].trim_start();
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return Err(SyntaxErrorKind::TrailingCharacters);
+    }
+    Ok(Some((key, Some(&quoted[..end]))))
+}
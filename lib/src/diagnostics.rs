@@ -0,0 +1,207 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Diagnostic rendering
+//!
+//! This provides rendering of the problems found in an [`Analysis`] in the style of compiler
+//! diagnostics, showing the command line with the offending part underlined, for example:
+//!
+//! ```text
+//! warning: unknown short option `z`
+//!     abc -hzx --foo
+//!           ^
+//! ```
+//!
+//! Color (with ANSI escape codes) is optional, with [`Renderer::from_env`] enabling it unless the
+//! `NO_COLOR` environment variable is set.
+//!
+//! Note, columns are counted in `char`s, thus alignment will be off for arguments containing
+//! combining or double-width characters.
+//!
+//! [`Analysis`]: ../analysis/struct.Analysis.html
+//! [`Renderer::from_env`]: struct.Renderer.html#method.from_env
+
+use std::fmt::Write;
+use super::analysis::*;
+use super::options::OptionsMode;
+use super::values::EnvSource;
+
+const INDENT: &str = "    ";
+const COLOR_ERROR: &str = "\u{1b}[1;31m";
+const COLOR_WARNING: &str = "\u{1b}[1;33m";
+const COLOR_RESET: &str = "\u{1b}[0m";
+
+/// Diagnostic renderer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Renderer {
+    /// Whether to use color (ANSI escape codes)
+    pub color: bool,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Renderer::PLAIN
+    }
+}
+
+impl Renderer {
+    /// Plain text renderer
+    pub const PLAIN: Renderer = Renderer { color: false };
+
+    /// Create a renderer, with color enabled unless the `NO_COLOR` variable is set (non-empty)
+    ///
+    /// Use [`ProcessEnv`](../values/struct.ProcessEnv.html) for the real process environment.
+    pub fn from_env<E>(env: &E) -> Self
+        where E: EnvSource + ?Sized
+    {
+        let no_color = env.var("NO_COLOR").is_some_and(|v| !v.is_empty());
+        Renderer { color: !no_color }
+    }
+
+    /// Renders a diagnostic for each problem item of the analysis
+    ///
+    /// The arguments and `mode` must be those used in processing. Gives an empty string if there
+    /// are no problems.
+    pub fn render<'a, A>(&self, args: &'a [A], mode: OptionsMode, analysis: &Analysis<'a>)
+        -> String
+        where A: 'a + AsRef<str>
+    {
+        let (line, columns) = command_line(args);
+        let spans = analysis.item_spans(args, mode);
+        let mut output = String::new();
+        for (item, span) in analysis.items.iter().zip(spans) {
+            let (error, message) = match describe(item, &span, args) {
+                Some(d) => d,
+                None => { continue; },
+            };
+            let (label, color) = match error {
+                true => ("error", COLOR_ERROR),
+                false => ("warning", COLOR_WARNING),
+            };
+            // Unexpected and invalid data is underlined itself, rather than the option
+            let (arg, range) = match *item {
+                ItemClass::Warn(ItemW::LongWithUnexpectedData { .. }) |
+                ItemClass::Err(ItemE::LongInvalidData { .. }) => {
+                    span.data.clone().unwrap_or((span.arg, span.range.clone()))
+                },
+                _ => (span.arg, span.range.clone()),
+            };
+            let arg_str = args[arg].as_ref();
+            let start = columns[arg] + arg_str[..range.start].chars().count();
+            let width = arg_str[range].chars().count();
+
+            let (color, reset) = match self.color {
+                true => (color, COLOR_RESET),
+                false => ("", ""),
+            };
+            let _ = writeln!(output, "{}{}{}: {}", color, label, reset, message);
+            let _ = writeln!(output, "{}{}", INDENT, line);
+            let _ = writeln!(output, "{}{}{}^{}{}", INDENT, " ".repeat(start), color,
+                "~".repeat(width.saturating_sub(1)), reset);
+        }
+        output
+    }
+}
+
+/// Builds the command line for display, returning it along with the starting column of each
+/// argument
+///
+/// Arguments that are empty or contain whitespace are quoted (with the column being that of the
+/// argument content).
+fn command_line<A: AsRef<str>>(args: &[A]) -> (String, Vec<usize>) {
+    let mut line = String::new();
+    let mut columns = Vec::with_capacity(args.len());
+    let mut column = 0;
+    for (i, arg) in args.iter().enumerate() {
+        let arg = arg.as_ref();
+        if i > 0 {
+            line.push(' ');
+            column += 1;
+        }
+        let quote = arg.is_empty() || arg.contains(char::is_whitespace);
+        if quote {
+            line.push('\'');
+            column += 1;
+        }
+        columns.push(column);
+        line.push_str(arg);
+        column += arg.chars().count();
+        if quote {
+            line.push('\'');
+            column += 1;
+        }
+    }
+    (line, columns)
+}
+
+/// Describes a problem item, giving whether error level, and the message
+///
+/// Gives `None` for non-problem items.
+fn describe<A: AsRef<str>>(item: &ItemClass, span: &ItemSpan, args: &[A])
+    -> Option<(bool, String)>
+{
+    // *Long option* as typed, with prefix, without data
+    let typed_long = || args[span.arg].as_ref()[..span.range.end].to_string();
+    Some(match *item {
+        ItemClass::Ok(_) => { return None; },
+        ItemClass::Err(ItemE::LongMissingData(..)) => {
+            (true, format!("option `{}` requires a value", typed_long()))
+        },
+        ItemClass::Err(ItemE::ShortMissingData(_, c)) => {
+            (true, format!("short option `{}` requires a value", c))
+        },
+        ItemClass::Err(ItemE::AmbiguousLong(..)) => {
+            (true, format!("ambiguous option `{}`", typed_long()))
+        },
+        ItemClass::Err(ItemE::LongInvalidData { d, v, .. }) => {
+            (true, format!("invalid value `{}` for option `{}` (possible values: {})", d,
+                typed_long(), v.join(", ")))
+        },
+        ItemClass::Warn(ItemW::UnknownLong(..)) => {
+            (false, format!("unknown option `{}`", typed_long()))
+        },
+        ItemClass::Warn(ItemW::UnknownShort(_, c)) => {
+            (false, format!("unknown short option `{}`", c))
+        },
+        ItemClass::Warn(ItemW::LongWithNoName(_)) => {
+            (false, String::from("option argument without a name"))
+        },
+        ItemClass::Warn(ItemW::LongWithUnexpectedData { .. }) => {
+            (false, format!("option `{}` does not take a value", typed_long()))
+        },
+        ItemClass::Warn(ItemW::LateOption(_)) => {
+            (false, String::from("option given after a non-option"))
+        },
+        ItemClass::Warn(ItemW::LongNotNegatable(..)) => {
+            (false, format!("option `{}` cannot be negated", typed_long()))
+        },
+        ItemClass::Warn(ItemW::ShortNotNegatable(_, c)) => {
+            (false, format!("short option `{}` cannot be negated", c))
+        },
+        ItemClass::Warn(ItemW::LongDeprecated(_, _, replacement)) => {
+            (false, deprecated(format!("option `{}`", typed_long()), replacement))
+        },
+        ItemClass::Warn(ItemW::ShortDeprecated(_, c, replacement)) => {
+            (false, deprecated(format!("short option `{}`", c), replacement))
+        },
+        ItemClass::Warn(ItemW::LongAbbreviated(_, n)) => {
+            let prefix = &args[span.arg].as_ref()[..span.range.start];
+            (false, format!("option `{}` is an abbreviation of `{}{}`", typed_long(), prefix, n))
+        },
+    })
+}
+
+/// Describes a deprecated option, with the replacement, if any
+fn deprecated(option: String, replacement: Option<&str>) -> String {
+    match replacement {
+        Some(replacement) => format!("{} is deprecated, use `{}` instead", option, replacement),
+        None => format!("{} is deprecated", option),
+    }
+}
@@ -11,10 +11,14 @@
 //! Documentation: Option support
 //!
 //! This crate has been designed around standard option conventions, and can process an argument
-//! list in two different common styles:
+//! list in several different common styles:
 //!
 //! - Standard: supporting traditional *long* and *short* options
 //! - Alternate: supporting *long options* only, with a single-dash prefix
+//! - DOS: supporting *long options* only, with a forward slash prefix, in DOS/Windows style
+//! - Long-only: as standard, but with single-dash *long options* also accepted, in the style of
+//!   glibc’s `getopt_long_only`
+//! - Custom: as described by a program supplied syntax description
 //!
 //! Basic feature support is on par with the C `getopt_long` function. (See the [overview] section
 //! for mention of the small differences).
@@ -44,7 +48,9 @@
 //! option is recognised as an “available” program option that takes a *data value*, as discussed
 //! below.
 //!
-//! Note, option name matching is case-sensitive.
+//! Note, option name matching is case-sensitive by default. For *long options*, case-insensitive
+//! matching, and treating dashes and underscores as equal, can be enabled with a name
+//! normalization policy (see [`Normalization`](../../options/struct.Normalization.html)).
 //!
 //! ## Data values
 //!
@@ -126,6 +132,97 @@
 //! **Note**: *Short options* can still be added to the option set in this mode, and it will still
 //! pass as valid; they will simply be ignored when performing matching.
 //!
+//! # DOS style
+//!
+//! This mode caters for programs whose users expect DOS/Windows conventions. As with *alternate*
+//! style, only *long options* are supported, here using a forward slash (`/`) as a prefix, i.e.
+//! `/help`. Otherwise the differences are as follows:
+//!
+//! - An in-same-argument *data value* can be separated from the name with either a colon or an
+//!   equals, i.e. `/o:file` or `/o=file` (the first of either is used).
+//! - Names are matched case-insensitively (ASCII case folding is applied unless the set’s name
+//!   normalization policy already specifies case folding).
+//! - A flag type option may be given with a `+` or `-` suffix, i.e. `/flag+` or `/flag-`. The
+//!   former is equivalent to `/flag`, the latter is reported as a negated match (`LongNegated`).
+//!   The suffix is only recognised as such where the name without it matches a flag type option.
+//! - `/?` is taken as a request for help, matching the option named `?` if there is one, otherwise
+//!   `help`.
+//!
+//! **Note**: Unix-style absolute paths (e.g. `/usr/bin`) given as *non-options* will look like
+//! options in this mode; where needed, users can place them after an *early terminator* (`--`).
+//!
+//! # Long-only style
+//!
+//! This mode mirrors glibc’s `getopt_long_only` function, as depended upon by X11-style and some
+//! older Unix programs. It is the same as *standard* style, except that an argument with a single
+//! dash prefix (e.g. `-foo`) is first tried as a *long option*, falling back to being taken as a
+//! *short option* set if that fails. Specifically:
+//!
+//! - A single character (e.g. `-x`) matching an available *short option* is always taken as such.
+//! - Otherwise, if the name component (i.e. ignoring any “in-argument” *data value*) matches an
+//!   available *long option*, it is taken as that *long option*. Abbreviations are included here
+//!   (if enabled), so `-he` would be taken as `--help` (even with `h` and `e` *short options*
+//!   available), and an ambiguous abbreviation is reported as such, with no fallback.
+//! - Otherwise, if the first character matches an available *short option*, the argument is
+//!   processed as a *short option* set just as in *standard* style, including the taking of a
+//!   *data value* by a data taking *short option* (e.g. `-ofile`).
+//! - Otherwise, it is reported as an unknown *long option*.
+//!
+//! Double-dash prefixed arguments are *long options* as usual.
+//!
+//! # Custom style
+//!
+//! Each of the above styles is defined by a predefined syntax description (`Syntax::STANDARD`,
+//! `Syntax::ALTERNATE`, `Syntax::DOS` and `Syntax::LONG_ONLY`). Programs with unusual conventions
+//! can describe their own and use it with the `OptionsMode::Custom` mode, choosing the *long
+//! option* prefix(es), the *short option* prefix (if any), the *early terminator* (if any), the
+//! *data value* separator character(s), the prefixes used for negated options, whether the DOS
+//! style case-insensitive matching, polarity suffixes and help alias are used, and whether the
+//! long-only style fallback is used. For example, a syntax using both `-` and
+//! `/` as *long option* prefixes and `:` as the separator:
+//!
+//! ```rust
+//! use gong::options::{OptionSet, OptionsMode, Syntax};
+//!
+//! static SYNTAX: Syntax = Syntax {
+//!     long_prefixes: &["-", "/"],
+//!     short_prefix: None,
+//!     data_separators: &[':'],
+//!     negated_long_prefix: None,
+//!     negated_short_prefix: None,
+//!     ..Syntax::STANDARD
+//! };
+//!
+//! let mut opts = OptionSet::EMPTY;
+//! opts.set_mode(OptionsMode::Custom(&SYNTAX));
+//! ```
+//!
+//! # Plus prefixed (negated) options
+//!
+//! Some programs (shell-like tools and `set`-style interfaces in particular) use `-x` to turn
+//! something on and `+x` to turn it off. Support for this can optionally be enabled for an option
+//! set (it is disabled by default), in which case, in *standard* style, arguments starting with a
+//! single plus (`+`) are taken to be sets of *short options*, and arguments starting with a double
+//! plus (`++`) are taken to be *long options*, in both cases reported as negated matches. In
+//! *alternate* style, a single plus is the *long option* prefix. (This does not apply to *DOS*
+//! style, which uses suffixes instead).
+//!
+//! Only options declared as *negatable* accept this form; others are reported with a warning.
+//! Options that take a *data value* cannot be negated, and no *data value* is consumed with the
+//! negated form. Plus prefixed arguments are otherwise interpreted just as their dash prefixed
+//! counterparts (including with respect to abbreviation). A lone `+` or `++` (in *standard* style)
+//! is a *non-option*.
+//!
+//! # Negative numbers
+//!
+//! By default an argument such as `-5` is, like any other dash prefixed argument, taken to be a set
+//! of *short options* (thus likely reported as unknown *short options*). Note that this is not a
+//! problem for *data values* consumed from the next argument, which are taken as-is. Optionally,
+//! for programs that accept numbers as *non-options*, arguments that are signed numbers (e.g. `-5`,
+//! `-3.14`, `-1e6`, or `+2`) can instead be taken to be *non-options*. Such a set should not have
+//! digits as *short options* (since they could not then be used on their own, only later in a set,
+//! such as `-x1`), which the validation report warns of (as a lint).
+//!
 //! # Abbreviated long option name matching
 //!
 //! Abbreviated *long option* name matching is supported, i.e. the feature that users can use an
@@ -147,6 +244,13 @@
 //!
 //! This is enabled by default, but can be opted out of when processing if not desired.
 //!
+//! It can also be controlled per option: an option can be excluded from abbreviated matching
+//! (for instance a destructive `--delete-all`), or be given a minimum abbreviation length. Such
+//! options are not candidates for matching an abbreviation that does not satisfy them, thus do not
+//! contribute to ambiguity. Where abbreviations are a future-compatibility hazard (new options
+//! could make a previously unique abbreviation ambiguous), a warning of each use of one can be
+//! enabled, with the `LongAbbreviated` item.
+//!
 //! # Utf-8 notes
 //!
 //! Native Utf-8 support in Rust makes handling Utf-8 strings largely trivial. It is important to
@@ -163,8 +267,12 @@
 //! first consists of simple latin characters only. The second and third use “umlauts” (diaeresis)
 //! above the `o`’s, however the first of these uses a `char` with the umlaut built in (`U+F6`) and
 //! the second uses the standard `o` (`U+6F`) followed by the special umlaut combining `char`
-//! (`U+0308`), thus they appear the same but are actually different “under the hood”. (It would not
-//! be efficient or worthwhile to try to handle the latter two as being the same option).
+//! (`U+0308`), thus they appear the same but are actually different “under the hood”. By default no
+//! attempt is made to handle the latter two as being the same option, however if the
+//! `unicode-normalization` crate feature is enabled, a name normalization policy (see
+//! [`Normalization`](../../options/struct.Normalization.html)) can be used to have names matched
+//! in Unicode NFC or NFKC normalized form. Names reported in the analysis remain in the form
+//! declared.
 //!
 //! Only single `char`s are supported for *short options*. A `char` paired with one or more special
 //! combinator/selector `char`s thus cannot be specified as an available *short option*. Such
@@ -51,6 +51,10 @@
 //!  * The “convenience” functionality of `-W foo` being treated as `--foo` is not supported
 //!    (unnecessary complexity).
 //!
+//! Conformance with glibc’s `getopt_long` is checked by the test suite (on GNU/Linux systems),
+//! which compares results for generated argument lists, with any other differences recorded in an
+//! allowlist there.
+//!
 //! ## Mismatch suggestions
 //!
 //! This library does not (currently) itself provide any suggestion mechanism for failed option
@@ -95,7 +95,85 @@
 //! a `set_mode` method is available.
 //!
 //! You can control whether or not to allow abbreviated matching with the `set_allow_abbreviations`
-//! method.
+//! method, with the `allow_abbreviation` and `min_abbreviation` attributes of a [`LongOption`]
+//! further controlling it per option, and can have each use of an abbreviation warned of with the
+//! `set_warn_abbreviations` method.
+//!
+//! By default, options may be freely intermixed with *non-options*. The `set_ordering` method
+//! allows choosing an alternative [`OrderingPolicy`], either to stop option processing once a
+//! given number of *non-options* have been encountered (with one, giving the POSIX behaviour that
+//! programs wrapping execution of another command need), or to have options given after a
+//! *non-option* flagged with a warning.
+//!
+//! Plus prefixed (negated) options (`+x`, `++foo`) can be enabled with the `set_plus_prefix`
+//! method, for options declared as `negatable` (see the `add_long_negatable` and
+//! `add_short_negatable` methods).
+//!
+//! Arguments that are signed numbers (e.g. `-5`) can be taken to be *non-options* rather than
+//! *short options* by enabling this with the `set_negative_numbers` method.
+//!
+//! *Long option* names are matched exactly by default. A [`Normalization`] policy can be set with
+//! the `set_normalization` method, to match them case-insensitively (ASCII only, or Unicode),
+//! and/or with dashes and underscores treated as equal (e.g. `--Dry_Run` matching `dry-run`).
+//!
+//! ## Possible values
+//!
+//! The *data values* a *long option* accepts can be restricted to a fixed list with its
+//! `possible_values` attribute, for instance `--color=<always|never|auto>`:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate gong;
+//! static OPTS: gong::options::OptionSet = gong_option_set_fixed!(
+//!     [ gong_longopt!("color", true; possible_values: &["always", "never", "auto"]) ], []
+//! );
+//! # fn main() {
+//! # }
+//! ```
+//!
+//! Values are matched just as option names are, thus with abbreviations allowed, `--color=al` is
+//! accepted, with the `LongWithData` item holding both the value as given (`al`) and the full
+//! value matched (`always`). Values not matching any possible value are reported with a
+//! `LongInvalidData` error item, which includes the list for use in error messages. The list is
+//! also offered by the [`completion`] module.
+//!
+//! Possible values are supported for *long options* only; *short option* data values are not
+//! checked.
+//!
+//! ## Retiring options
+//!
+//! Options can be retired gracefully with their `status` attribute ([`OptionStatus`]). *Hidden*
+//! options are matched as usual, but are not offered by completion (and should be left out of
+//! help output, per `OptionStatus::is_hidden`). *Deprecated* options are also matched, but are
+//! followed by a `LongDeprecated` or `ShortDeprecated` warning item, naming the replacement, if
+//! any; they remain visible in completion (and help), being still in use. *Legacy* options are
+//! accepted silently (along with any *data value*), but are otherwise ignored, with no items given
+//! for them, for options that no longer do anything but must not break existing scripts.
+//!
+//! ## Positionals
+//!
+//! Optionally, the *non-option* (positional) arguments a program expects can also be described,
+//! as a list of named [`Positional`]s, each with a minimum and maximum number of arguments it
+//! accepts (the last may be unlimited). These can be added with the `add_positional`,
+//! `add_optional_positional` and `add_variadic_positional` methods of [`OptionSetEx`], or with the
+//! `gong_positional` macro, given after a semi-colon in the set construction macros:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate gong;
+//! static OPTS: gong::options::OptionSet = gong_option_set_fixed!(
+//!     [ gong_longopt!("help") ], [];
+//!     positionals: &[
+//!         gong_positional!("INPUT"),              // Exactly one
+//!         gong_positional!("FILE", 0, None),      // Zero or more
+//!     ]
+//! );
+//! # fn main() {
+//! assert_eq!(OPTS.synopsis("prog"), "prog [OPTIONS] INPUT [FILE]...");
+//! # }
+//! ```
+//!
+//! As shown, the same declarations are used in generating a usage synopsis.
 //!
 //! ## Validation
 //!
@@ -108,6 +186,37 @@
 //! Some basic validation is also performed directly by the `add_*` methods on [`OptionSetEx`], but
 //! this does not cover checking for duplicates.
 //!
+//! A `static` set can instead be checked at compile time, by constructing it with the
+//! `gong_option_set_checked!` macro in place of `gong_option_set_fixed!`, such that an invalid set
+//! fails to compile. This uses the `const fn` `OptionSet::first_flaw` check, which compares *long
+//! option* names for duplicates with only ASCII case folding and separator folding applied, so if
+//! you use fuller (Unicode) normalization, still also use `validate` to catch all duplicates.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate gong;
+//! static OPTS: gong::options::OptionSet = gong_option_set_checked!(
+//!     [ gong_longopt!("help"), gong_longopt!("verbose") ], [ gong_shortopt!('h') ]
+//! );
+//! # fn main() {
+//! # }
+//! ```
+//!
+//! For more detail, the `validation_report` method gives a list of findings, each identifying the
+//! option concerned (by index in its list) and having a severity, along with a `Display`
+//! implementation for a message. Beyond the problems given by `validate` (errors), it covers
+//! *lints*, which are valid, but likely mistakes, such as names with a leading dash, whitespace,
+//! control or invisible characters, or characters easily confused with ASCII ones (e.g. Cyrillic
+//! `а`), and *short options* declared with a mode that does not use them. This is best used in a
+//! test, failing on any finding:
+//!
+//! ```rust
+//! # let opts: gong::options::OptionSetEx = Default::default();
+//! for finding in opts.validation_report() {
+//!     panic!("{}", finding);
+//! }
+//! ```
+//!
 //! **Note**: With respect to what is or is not a duplicate, only the name/`char` matters; the
 //! `expects_data` attribute makes no difference.
 //!
@@ -133,6 +242,19 @@
 //! **Note**: Of course you do not have to provide the real program args, you can provide any set of
 //! `String` objects, and you can even of course take the real set and modify it first if you wish.
 //!
+//! ## Response files
+//!
+//! If wanted, *response file* arguments (`@path`, as supported by many compilers) can be expanded
+//! into the arguments held in the file they reference, before processing, with the [`argfiles`]
+//! module. The expansion records the origin (command line argument, or file and line) of each
+//! resulting argument, thus argument indices in the analysis can still be traced back.
+//!
+//! ```rust
+//! # let args: Vec<String> = std::env::args().skip(1).collect();
+//! let expansion = gong::argfiles::expand(&args).expect("failed to read response file");
+//! let args = expansion.args;
+//! ```
+//!
 //! # Step #3: Processing
 //!
 //! With input args gathered and “available” option set constructed, now you’re ready for analysis.
@@ -150,6 +272,11 @@
 //! choose), calling `process` for each. (Naturally though you must beware the complications
 //! handling “in-next-arg” *data values* doing this).
 //!
+//! Processing searches the option set linearly for each option found, which is of no concern for
+//! typical programs, but for very large sets (thousands of options) you can instead build a
+//! [`CompiledOptionSet`] from your option set once, which indexes the options for fast lookup, and
+//! call its `process` method, with identical results. It can be shared between threads.
+//!
 //! # Step #4: Take action
 //!
 //! It is now up to you to take appropriate action in response to what was found.
@@ -166,6 +293,120 @@
 //! applicable with *data values* as to whether the data arg was located in the same argument or the
 //! next.
 //!
+//! If positionals were declared, the *non-options* of the analysis can be bound to them with the
+//! [`Analysis`] object’s `bind_positionals` method, which reports each value against the name of
+//! the positional it was bound to, along with errors for any missing or excess *non-options*.
+//!
+//! Should you want to log or forward a normalised form of the command line, the [`Analysis`]
+//! object’s `canonical_args` method rebuilds an equivalent argument list, with abbreviated *long
+//! options* expanded, *short option* sets split, and *data values* given in a uniform form (either
+//! in the same argument, or the next), with the *early terminator* and *non-options* preserved.
+//!
+//! For precise tooling (e.g. highlighting problems within the command line), the [`Analysis`]
+//! object’s `item_spans` method gives the location of each item, as byte ranges within its
+//! argument (and that of any *data value*), along with the text actually typed (e.g. an
+//! abbreviated *long option* name), while `arg_roles` describes the role of each argument (option,
+//! *data value* consumed by a given item, *non-option*, or *early terminator*).
+//!
+//! For reporting problems to users, the [`diagnostics`] module renders compiler style messages,
+//! showing the command line with the offending part underlined, optionally in color (respecting
+//! the `NO_COLOR` convention):
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate gong;
+//! use gong::diagnostics::Renderer;
+//! use gong::values::ProcessEnv;
+//!
+//! static OPTS: gong::options::OptionSet = gong_option_set_fixed!([ gong_longopt!("foo") ], []);
+//! # fn main() {
+//! # let args: Vec<String> = std::env::args().skip(1).collect();
+//! let analysis = OPTS.process(&args);
+//! if analysis.error || analysis.warn {
+//!     eprint!("{}", Renderer::from_env(&ProcessEnv).render(&args, OPTS.mode, &analysis));
+//! }
+//! # }
+//! ```
+//!
+//! ## Completion
+//!
+//! The [`completion`] module provides completion of partially typed command lines, for serving
+//! dynamic completions to any shell (for instance through a hidden `--complete` option). Given the
+//! arguments and the index of the one being completed, it gives the matching option names, or
+//! indicates that the argument is a *data value* for a given option, or a *non-option*, with the
+//! same semantics as processing.
+//!
+//! ## Wrapper programs
+//!
+//! Programs that consume some options and forward everything else on to a child process can enable
+//! pass-through in the option set (`set_pass_through`). Unknown options are then reported with
+//! `PassThrough` items holding the original argument verbatim (any “in-argument” *data value*
+//! included), rather than as unknown option warnings, and a *short option* set containing any
+//! unknown option is passed through as a whole. The [`Analysis`] object’s `leftover_args` method
+//! then gives the arguments to forward, being those passed through along with the *non-options*,
+//! in order. (Where an unknown option takes its *data value* from the next argument, this is
+//! forwarded as a *non-option*).
+//!
+//! ## Value resolution and environment variables
+//!
+//! Rather than working through the items directly, the values of *long options* can be collected
+//! into an [`OptionValues`] object, which can then be queried by option name. Options can also be
+//! bound to an environment variable (the `env` attribute of [`LongOption`]), to be used as a
+//! fallback when not given on the command line, and each value records where it came from:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate gong;
+//! use gong::values::{OptionValues, ProcessEnv};
+//!
+//! static OPTS: gong::options::OptionSet = gong_option_set_fixed!(
+//!     [ gong_longopt!("token", true; env: Some("MYTOOL_TOKEN")) ], []
+//! );
+//! # fn main() {
+//! # let args: Vec<String> = std::env::args().skip(1).collect();
+//! let analysis = OPTS.process(&args);
+//! let mut values = OptionValues::from_analysis(&analysis);
+//! values.add_env(&OPTS, &ProcessEnv);
+//! if let Some(token) = values.get("token") {
+//!     println!("using token from {}", token.source);
+//! }
+//! # }
+//! ```
+//!
+//! The environment is read through the [`EnvSource`] trait, thus a fake one can be substituted,
+//! for instance in tests. Variables holding a value that is not one of the option’s possible values
+//! are not used, being collected in the `invalid` list instead, for reporting.
+//!
+//! Values can further be filled in from configuration files, with the [`config`] module. These use
+//! a simple `name = value` format (one entry per line), and are analysed against the option set,
+//! just as with arguments, with problems reported against line numbers. The command line takes
+//! precedence over environment variables, which take precedence over configuration files, with
+//! files added later overriding those added earlier:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate gong;
+//! use gong::config::ConfigFile;
+//! use gong::values::OptionValues;
+//!
+//! static OPTS: gong::options::OptionSet = gong_option_set_fixed!(
+//!     [ gong_longopt!("token", true) ], []
+//! );
+//! # fn main() {
+//! # let args: Vec<String> = std::env::args().skip(1).collect();
+//! let analysis = OPTS.process(&args);
+//! let config = ConfigFile::new("tool.conf", String::from("token = abc"));
+//! let config_analysis = config.analyse(&OPTS).expect("config file syntax error");
+//! let mut values = OptionValues::from_analysis(&analysis);
+//! values.add_config(&config, &config_analysis);
+//! # }
+//! ```
+//!
+//! Finally, data taking options can declare a default value (the `default` attribute of
+//! [`LongOption`]), filled in with the `add_defaults` method, taking the lowest precedence. Values
+//! taken from defaults are marked as such (`ValueSource::Default`), thus help output and dumps of
+//! the effective configuration can tell declared defaults apart from values actually given.
+//!
 //! **Note**: some item variants that may be returned in the [`Analysis`] struct hold `&str`
 //! references to strings that were provided in the argument and option data provided to `process`.
 //! This is done for efficiency. Beware of this with respect to lifetimes.
@@ -185,5 +426,17 @@
 //! [`Analysis`]: ../../analysis/struct.Analysis.html
 //! [`OptionSet`]: ../../options/struct.OptionSet.html
 //! [`OptionSetEx`]: ../../options/struct.OptionSetEx.html
+//! [`OptionStatus`]: ../../options/enum.OptionStatus.html
+//! [`Positional`]: ../../options/struct.Positional.html
+//! [`LongOption`]: ../../options/struct.LongOption.html
+//! [`OptionValues`]: ../../values/struct.OptionValues.html
+//! [`OrderingPolicy`]: ../../options/enum.OrderingPolicy.html
+//! [`Normalization`]: ../../options/struct.Normalization.html
+//! [`completion`]: ../../completion/index.html
+//! [`diagnostics`]: ../../diagnostics/index.html
+//! [`EnvSource`]: ../../values/trait.EnvSource.html
+//! [`config`]: ../../config/index.html
+//! [`CompiledOptionSet`]: ../../compiled/struct.CompiledOptionSet.html
+//! [`argfiles`]: ../../argfiles/index.html
 //! [`OptionSet::to_extendible`]: ../../options/struct.OptionSet.html#method.to_extendible
 //! [`OptionSetEx::as_fixed`]: ../../options/struct.OptionSetEx.html#method.as_fixed
@@ -12,9 +12,7 @@ use std::convert::AsRef;
 use super::options::*;
 use super::analysis::*;
 
-const SINGLE_DASH_PREFIX: &str = "-";
-const DOUBLE_DASH_PREFIX: &str = "--";
-const EARLY_TERMINATOR: &str = "--";
+const HELP_OPTION: &str = "help";
 
 /// Basic argument type
 ///
@@ -24,6 +22,48 @@ enum ArgTypeBasic<'a> {
     EarlyTerminator,
     LongOption(&'a str),
     ShortOptionSet(&'a str),
+    LongOptionNegated(&'a str),
+    ShortOptionSetNegated(&'a str),
+}
+
+/// Result of matching a long option name
+pub(crate) enum LongMatch<'o, 'a: 'o> {
+    /// Matched (exactly, or as a unique abbreviation)
+    Found(&'o LongOption<'a>),
+    /// Multiple abbreviated matches, without an exact match
+    Ambiguous,
+    /// No match
+    None,
+}
+
+/// Option lookup
+///
+/// This allows an indexed form of an option set to provide faster matching than the linear search
+/// done with a plain option set, with identical results.
+pub(crate) trait Lookup<'r, 'a: 'r> {
+    /// The option set
+    fn options(&self) -> &OptionSet<'r, 'a>;
+    /// Finds the long option matching `name`, which may be an abbreviation (if allowed)
+    ///
+    /// The normalization given must be that in effect for the option set, accounting for the mode
+    /// (see `OptionSet::name_normalization`).
+    fn find_long(&self, normalization: &Normalization, name: &str) -> LongMatch<'r, 'a>;
+    /// Finds the short option matching `ch`
+    fn find_short(&self, ch: char) -> Option<&'r ShortOption>;
+}
+
+impl<'r, 'a: 'r> Lookup<'r, 'a> for OptionSet<'r, 'a> {
+    fn options(&self) -> &OptionSet<'r, 'a> {
+        self
+    }
+
+    fn find_long(&self, normalization: &Normalization, name: &str) -> LongMatch<'r, 'a> {
+        find_long(self, normalization, name)
+    }
+
+    fn find_short(&self, ch: char) -> Option<&'r ShortOption> {
+        self.short.iter().find(|candidate| candidate.ch == ch)
+    }
 }
 
 /// Analyses provided program arguments, using provided information about valid available options.
@@ -34,36 +74,71 @@ enum ArgTypeBasic<'a> {
 ///
 /// Expects available `options` data to have already been validated. (See
 /// [`OptionSet::is_valid`](options/struct.OptionSet.html#method.is_valid)).
-pub(crate) fn process<'o, 'r, 'a, A>(args: &'a [A], options: &'o OptionSet<'r, 'a>) -> Analysis<'a>
+pub(crate) fn process<'r, 'a, A, L>(args: &'a [A], lookup: &L) -> Analysis<'a>
     where A: 'a + AsRef<str>,
-          'r: 'o, 'a: 'r
+          L: Lookup<'r, 'a> + ?Sized,
+          'a: 'r
 {
     /* NOTE: We deliberately do not perform validation of the provided `options` data within this
      * function; the burden to do so is left to the user. The choice to not do this is for reasons
      * of efficiency - to not waste energy on known good sets, and to avoid waste of energy if this
      * function is called multiple times with the same set. */
 
-    let get_basic_arg_type_fn = match options.mode {
-        OptionsMode::Standard => get_basic_arg_type_standard,
-        OptionsMode::Alternate => get_basic_arg_type_alternate,
-    };
+    let options = lookup.options();
+    let syntax = options.mode.syntax();
+    let data_separators = syntax.data_separators;
+    let normalization = options.name_normalization();
 
     let mut results = Analysis::new(args.len());
     let mut early_terminator_encountered = false;
 
+    // Ordering policy tracking. With the “stop” policy, once the limit is reached, all remaining
+    // arguments are treated as *non-options*, just as with an early terminator.
+    let mut non_options_encountered = 0;
+    let mut options_stopped = options.ordering == OrderingPolicy::StopAtNonOption(0);
+    let warn_late = options.ordering == OrderingPolicy::WarnLateOptions;
+    // Late option argument awaiting a warning (its index, and the position of its first item), to
+    // be given only if it produces items (not so for legacy options, which are ignored)
+    let mut late: Option<(usize, usize)> = None;
+
     let mut arg_iter = args.iter().enumerate();
 
     while let Some((arg_index, arg_non_ref)) = arg_iter.next() {
+        note_late(&mut results, late.take());
         let arg_ref = arg_non_ref.as_ref();
 
-        let arg_type = match early_terminator_encountered {
+        let arg_type = match early_terminator_encountered || options_stopped {
             true => ArgTypeBasic::NonOption,
-            false => get_basic_arg_type_fn(arg_ref),
+            false if options.negative_numbers && is_signed_number(arg_ref) => {
+                ArgTypeBasic::NonOption
+            },
+            false => match get_basic_arg_type(syntax, arg_ref) {
+                ArgTypeBasic::NonOption if options.plus_prefix => {
+                    get_negated_arg_type(syntax, arg_ref)
+                },
+                ArgTypeBasic::ShortOptionSet(optset_string) if syntax.long_first => {
+                    get_long_first_arg_type(lookup, &normalization, data_separators,
+                        optset_string)
+                },
+                arg_type => arg_type,
+            },
         };
 
+        match arg_type {
+            ArgTypeBasic::NonOption | ArgTypeBasic::EarlyTerminator => {},
+            _ if warn_late && non_options_encountered > 0 => {
+                late = Some((arg_index, results.items.len()));
+            },
+            _ => {},
+        }
+
         match arg_type {
             ArgTypeBasic::NonOption => {
                 results.add(ItemClass::Ok(Item::NonOption(arg_index, arg_ref)));
+                non_options_encountered += 1;
+                if let OrderingPolicy::StopAtNonOption(limit) = options.ordering {
+                    options_stopped = non_options_encountered >= limit;
+                }
             },
             ArgTypeBasic::EarlyTerminator => {
                 early_terminator_encountered = true;
@@ -73,21 +148,12 @@ pub(crate) fn process<'o, 'r, 'a, A>(args: &'a [A], options: &'o OptionSet<'r, '
             },
             ArgTypeBasic::LongOption(opt_string) => {
                 /* We need to deal with the fact that arg data may be supplied in the same argument,
-                 * separated by an `=`, and also that the user is allowed to supply an abbreviated
-                 * form of an available option, so long as it is unique, which requires checking for
-                 * ambiguity. (See documentation). */
+                 * separated by an `=` (or other separator per the syntax), and also that the user
+                 * is allowed to supply an abbreviated form of an available option, so long as it is
+                 * unique, which requires checking for ambiguity. (See documentation). */
 
                 // Extract name, splitting from optional “in-same-arg” data value
-                let (name, data_included) = match opt_string.find('=') {
-                    None => (opt_string, None),
-                    Some(i) => {
-                        let split = opt_string.split_at(i);
-                        // We know that the `=` is encoded as just one byte and that it is
-                        // definately there, so we can safely skip it unchecked.
-                        let data_included = unsafe { split.1.get_unchecked(1..) };
-                        (split.0, Some(data_included))
-                    },
-                };
+                let (name, data_included) = split_long_data(opt_string, data_separators);
 
                 // This occurs with `--=` or `--=foo` (`-=` or `-=foo` in alt mode)
                 if name.is_empty() {
@@ -96,96 +162,205 @@ pub(crate) fn process<'o, 'r, 'a, A>(args: &'a [A], options: &'o OptionSet<'r, '
                     continue;
                 }
 
-                let mut matched: Option<&LongOption> = None;
-                let mut ambiguity = false;
-                for candidate in options.long {
-                    // Exact
-                    if candidate.name == name {
-                        // An exact match overrules a previously found partial match and ambiguity
-                        // found with multiple partial matches.
-                        matched = Some(candidate);
-                        ambiguity = false;
-                        break;
-                    }
-                    // Abbreviated
-                    else if options.allow_abbreviations && !ambiguity
-                        && name.len() < candidate.name.len()
-                        && candidate.name.starts_with(name)
-                    {
-                        match matched {
-                            Some(_) => { ambiguity = true; },
-                            None => { matched = Some(candidate); },
+                // Polarity suffixes (e.g. `/flag+`, `/flag-`) and help alias (e.g. `/?`)
+                if data_included.is_none() {
+                    let polarity = polarity(lookup, syntax, &normalization, name);
+                    if let Some((flag, negated, matched_name)) = polarity {
+                        if flag.status != OptionStatus::Legacy {
+                            results.add(ItemClass::Ok(match negated {
+                                false => Item::Long(arg_index, flag.name),
+                                true => Item::LongNegated(arg_index, flag.name),
+                            }));
+                            note_abbreviated(&mut results, options, &normalization, arg_index,
+                                flag, matched_name);
+                            note_deprecated_long(&mut results, arg_index, flag);
                         }
+                        continue;
                     }
                 }
 
-                if ambiguity {
-                    results.add(ItemClass::Err(ItemE::AmbiguousLong(arg_index, name)));
-                    results.error = true;
-                }
-                else if let Some(matched) = matched {
-                    // Use option’s full name, not the possibly abbreviated user provided one
-                    let opt_name = &matched.name;
-
-                    if matched.expects_data {
-                        // Data included in same argument
-                        // We accept it even if it’s an empty string
-                        if let Some(data) = data_included {
-                            results.add(ItemClass::Ok(Item::LongWithData {
-                                i: arg_index, n: opt_name, d: data, l: DataLocation::SameArg }));
+                match lookup.find_long(&normalization, name) {
+                    LongMatch::Ambiguous => {
+                        results.add(ItemClass::Err(ItemE::AmbiguousLong(arg_index, name)));
+                        results.error = true;
+                    },
+                    // Ignored, though any data must still be consumed
+                    LongMatch::Found(matched) if matched.status == OptionStatus::Legacy => {
+                        if matched.expects_data && data_included.is_none() {
+                            arg_iter.next();
                         }
-                        // Data included in next argument
-                        else if let Some((_, next_arg)) = arg_iter.next() {
-                            results.add(ItemClass::Ok(Item::LongWithData {
-                                i: arg_index, n: opt_name, d: next_arg.as_ref(),
-                                l: DataLocation::NextArg }));
+                    },
+                    LongMatch::Found(matched) => {
+                        // Use option’s full name, not the possibly abbreviated user provided one
+                        let opt_name = &matched.name;
+
+                        if matched.expects_data {
+                            let data = match data_included {
+                                // Data included in same argument
+                                // We accept it even if it’s an empty string
+                                Some(data) => Some((data, DataLocation::SameArg)),
+                                // Data included in next argument
+                                None => arg_iter.next().map(|(_, next_arg)| {
+                                    (next_arg.as_ref(), DataLocation::NextArg)
+                                }),
+                            };
+                            match data {
+                                Some((data, l)) => {
+                                    let abbreviations = options.allow_abbreviations;
+                                    let value = match_value(matched, data, abbreviations);
+                                    match value {
+                                        Some(value) => {
+                                            results.add(ItemClass::Ok(Item::LongWithData {
+                                                i: arg_index, n: opt_name, d: data, v: value, l }));
+                                        },
+                                        None => {
+                                            results.add(ItemClass::Err(ItemE::LongInvalidData {
+                                                i: arg_index, n: opt_name, d: data, l,
+                                                v: matched.possible_values }));
+                                            results.error = true;
+                                        },
+                                    }
+                                },
+                                // Data missing
+                                None => {
+                                    results.add(ItemClass::Err(ItemE::LongMissingData(arg_index,
+                                        opt_name)));
+                                    results.error = true;
+                                },
+                            }
                         }
-                        // Data missing
                         else {
-                            results.add(ItemClass::Err(ItemE::LongMissingData(arg_index, opt_name)));
-                            results.error = true;
+                            match data_included {
+                                None |
+                                // Ignore unexpected data if empty string
+                                Some("") => {
+                                    results.add(ItemClass::Ok(Item::Long(arg_index, opt_name)));
+                                },
+                                Some(data) => {
+                                    results.add(ItemClass::Warn(ItemW::LongWithUnexpectedData {
+                                        i: arg_index, n: opt_name, d: data }));
+                                    results.warn = true;
+                                },
+                            }
                         }
-                    }
-                    else {
+                        note_abbreviated(&mut results, options, &normalization, arg_index, matched,
+                            name);
+                        note_deprecated_long(&mut results, arg_index, matched);
+                    },
+                    LongMatch::None if options.pass_through => {
+                        results.add(ItemClass::Ok(Item::PassThrough(arg_index, arg_ref)));
+                    },
+                    LongMatch::None => {
+                        // Again, we ignore any possibly included data in the argument
+                        results.add(ItemClass::Warn(ItemW::UnknownLong(arg_index, name)));
+                        results.warn = true;
+                    },
+                }
+            },
+            ArgTypeBasic::LongOptionNegated(opt_string) => {
+                // Data is never expected with the negated form, but we must split it off to get
+                // the name
+                let (name, data_included) = split_long_data(opt_string, data_separators);
+
+                // This occurs with `++=` or `++=foo` (`+=` or `+=foo` in alt mode)
+                if name.is_empty() {
+                    results.add(ItemClass::Warn(ItemW::LongWithNoName(arg_index)));
+                    results.warn = true;
+                    continue;
+                }
+
+                match lookup.find_long(&normalization, name) {
+                    LongMatch::Ambiguous => {
+                        results.add(ItemClass::Err(ItemE::AmbiguousLong(arg_index, name)));
+                        results.error = true;
+                    },
+                    LongMatch::Found(matched) if matched.status == OptionStatus::Legacy => {},
+                    LongMatch::Found(matched) if matched.negatable && !matched.expects_data => {
                         match data_included {
                             None |
                             // Ignore unexpected data if empty string
                             Some("") => {
-                                results.add(ItemClass::Ok(Item::Long(arg_index, opt_name)));
+                                results.add(ItemClass::Ok(Item::LongNegated(arg_index,
+                                    matched.name)));
                             },
                             Some(data) => {
                                 results.add(ItemClass::Warn(ItemW::LongWithUnexpectedData {
-                                    i: arg_index, n: opt_name, d: data }));
+                                    i: arg_index, n: matched.name, d: data }));
                                 results.warn = true;
                             },
                         }
-                    }
+                        note_abbreviated(&mut results, options, &normalization, arg_index, matched,
+                            name);
+                        note_deprecated_long(&mut results, arg_index, matched);
+                    },
+                    LongMatch::Found(matched) => {
+                        results.add(ItemClass::Warn(ItemW::LongNotNegatable(arg_index,
+                            matched.name)));
+                        results.warn = true;
+                    },
+                    LongMatch::None if options.pass_through => {
+                        results.add(ItemClass::Ok(Item::PassThrough(arg_index, arg_ref)));
+                    },
+                    LongMatch::None => {
+                        results.add(ItemClass::Warn(ItemW::UnknownLong(arg_index, name)));
+                        results.warn = true;
+                    },
                 }
-                else {
-                    // Again, we ignore any possibly included data in the argument
-                    results.add(ItemClass::Warn(ItemW::UnknownLong(arg_index, name)));
-                    results.warn = true;
+            },
+            ArgTypeBasic::ShortOptionSetNegated(optset_string)
+                if options.pass_through && has_unknown_short(lookup, optset_string, true) =>
+            {
+                results.add(ItemClass::Ok(Item::PassThrough(arg_index, arg_ref)));
+            },
+            ArgTypeBasic::ShortOptionSetNegated(optset_string) => {
+                // No data is taken with the negated form, so every `char` is an option
+                for ch in optset_string.chars() {
+                    match lookup.find_short(ch) {
+                        None => {
+                            results.add(ItemClass::Warn(ItemW::UnknownShort(arg_index, ch)));
+                            results.warn = true;
+                        },
+                        Some(matched) if matched.status == OptionStatus::Legacy => {},
+                        Some(matched) if matched.negatable && !matched.expects_data => {
+                            results.add(ItemClass::Ok(Item::ShortNegated(arg_index, ch)));
+                            note_deprecated_short(&mut results, arg_index, matched);
+                        },
+                        Some(_) => {
+                            results.add(ItemClass::Warn(ItemW::ShortNotNegatable(arg_index, ch)));
+                            results.warn = true;
+                        },
+                    }
                 }
             },
+            ArgTypeBasic::ShortOptionSet(optset_string)
+                if options.pass_through && has_unknown_short(lookup, optset_string, false) =>
+            {
+                results.add(ItemClass::Ok(Item::PassThrough(arg_index, arg_ref)));
+            },
             ArgTypeBasic::ShortOptionSet(optset_string) => {
                 let last_char_index = optset_string.chars().count() - 1;
                 for (i, (byte_pos, ch)) in optset_string.char_indices().enumerate() {
-                    let mut match_found = false;
-                    let mut expects_data = false;
-                    for candidate in options.short {
-                        if candidate.ch == ch {
-                            match_found = true;
-                            expects_data = candidate.expects_data;
+                    let matched = match lookup.find_short(ch) {
+                        Some(matched) => matched,
+                        None => {
+                            results.add(ItemClass::Warn(ItemW::UnknownShort(arg_index, ch)));
+                            results.warn = true;
+                            continue;
+                        },
+                    };
+
+                    // Ignored, though any data must still be consumed
+                    if matched.status == OptionStatus::Legacy {
+                        if matched.expects_data {
+                            if i == last_char_index {
+                                arg_iter.next();
+                            }
                             break;
                         }
                     }
-
-                    if !match_found {
-                        results.add(ItemClass::Warn(ItemW::UnknownShort(arg_index, ch)));
-                        results.warn = true;
-                    }
-                    else if !expects_data {
+                    else if !matched.expects_data {
                         results.add(ItemClass::Ok(Item::Short(arg_index, ch)));
+                        note_deprecated_short(&mut results, arg_index, matched);
                     }
                     else {
                         // If not last char, remaining chars are our data
@@ -194,6 +369,7 @@ pub(crate) fn process<'o, 'r, 'a, A>(args: &'a [A], options: &'o OptionSet<'r, '
                             let data = optset_string.split_at(next_char_byte_pos).1;
                             results.add(ItemClass::Ok(Item::ShortWithData {
                                 i: arg_index, c: ch, d: data, l: DataLocation::SameArg }));
+                            note_deprecated_short(&mut results, arg_index, matched);
                             break;
                         }
                         // Data included in next argument
@@ -207,11 +383,13 @@ pub(crate) fn process<'o, 'r, 'a, A>(args: &'a [A], options: &'o OptionSet<'r, '
                             results.add(ItemClass::Err(ItemE::ShortMissingData(arg_index, ch)));
                             results.error = true;
                         }
+                        note_deprecated_short(&mut results, arg_index, matched);
                     }
                 }
             },
         }
     }
+    note_late(&mut results, late);
     results
 }
 
@@ -220,6 +398,7 @@ pub(crate) fn process<'o, 'r, 'a, A>(args: &'a [A], options: &'o OptionSet<'r, '
 // This is similar to a `starts_with` check, but the length must be longer than the prefix, equal
 // length is no good.
 #[inline(always)]
+#[allow(clippy::op_ref, clippy::redundant_slicing)]
 fn has_prefix(arg: &str, prefix: &str) -> bool {
     // Note, it is safe to index into `arg` in here; we don’t care about char boundaries for the
     // simple byte-slice comparison. Doing this is optimally efficient, avoiding `start_with`’s
@@ -228,31 +407,273 @@ fn has_prefix(arg: &str, prefix: &str) -> bool {
     arg.len() > prefix_len && &prefix.as_bytes()[..] == &arg.as_bytes()[..prefix_len]
 }
 
-/// Assess argument type, returning options without their prefix, for “standard” mode
-fn get_basic_arg_type_standard<'a>(arg: &'a str) -> ArgTypeBasic<'a> {
-    if arg == EARLY_TERMINATOR {
-        ArgTypeBasic::EarlyTerminator
+/// Assess argument type, returning options without their prefix, per the given syntax
+fn get_basic_arg_type<'a>(syntax: &Syntax, arg: &'a str) -> ArgTypeBasic<'a> {
+    if Some(arg) == syntax.early_terminator {
+        return ArgTypeBasic::EarlyTerminator;
     }
-    else if has_prefix(arg, DOUBLE_DASH_PREFIX) {
-        ArgTypeBasic::LongOption(unsafe { arg.get_unchecked(DOUBLE_DASH_PREFIX.len()..) })
+    for prefix in syntax.long_prefixes {
+        if has_prefix(arg, prefix) {
+            return ArgTypeBasic::LongOption(unsafe { arg.get_unchecked(prefix.len()..) });
+        }
     }
-    else if has_prefix(arg, SINGLE_DASH_PREFIX) {
-        ArgTypeBasic::ShortOptionSet(unsafe { arg.get_unchecked(SINGLE_DASH_PREFIX.len()..) })
+    match syntax.short_prefix {
+        Some(prefix) if has_prefix(arg, prefix) => {
+            ArgTypeBasic::ShortOptionSet(unsafe { arg.get_unchecked(prefix.len()..) })
+        },
+        _ => ArgTypeBasic::NonOption,
     }
-    else {
-        ArgTypeBasic::NonOption
+}
+
+/// Assess argument type, for negated (e.g. plus prefixed) options, returning options without their
+/// prefix, per the given syntax
+///
+/// This is used (if enabled) for arguments that would otherwise be *non-options*.
+fn get_negated_arg_type<'a>(syntax: &Syntax, arg: &'a str) -> ArgTypeBasic<'a> {
+    match (syntax.negated_long_prefix, syntax.negated_short_prefix) {
+        // A lone long prefix (e.g. `++`) is left alone, rather than being taken as a short option
+        // set
+        (Some(prefix), _) if arg == prefix => ArgTypeBasic::NonOption,
+        (Some(prefix), _) if has_prefix(arg, prefix) => {
+            ArgTypeBasic::LongOptionNegated(unsafe { arg.get_unchecked(prefix.len()..) })
+        },
+        (_, Some(prefix)) if has_prefix(arg, prefix) => {
+            ArgTypeBasic::ShortOptionSetNegated(unsafe { arg.get_unchecked(prefix.len()..) })
+        },
+        _ => ArgTypeBasic::NonOption,
+    }
+}
+
+/// Resolves a *short option* set argument for syntaxes where such arguments are first tried as a
+/// *long option* (e.g. long-only mode)
+///
+/// The argument is taken as a *long option* if its name component matches one (exactly, or as an
+/// abbreviation, including ambiguously), unless it is a single character matching a *short option*.
+/// Otherwise it falls back to being a *short option* set, unless its first character does not
+/// match a *short option*, in which case it is reported as an unknown *long option*.
+fn get_long_first_arg_type<'r, 'a, 's, L>(lookup: &L, normalization: &Normalization,
+    data_separators: &[char], optset_string: &'s str) -> ArgTypeBasic<'s>
+    where L: Lookup<'r, 'a> + ?Sized, 'a: 'r
+{
+    let is_short = |ch: char| lookup.find_short(ch).is_some();
+    let mut chars = optset_string.chars();
+    let first = chars.next().expect("set cannot be empty");
+    if chars.next().is_none() && is_short(first) {
+        return ArgTypeBasic::ShortOptionSet(optset_string);
+    }
+    let (name, _) = split_long_data(optset_string, data_separators);
+    if !name.is_empty() {
+        if let LongMatch::Found(_) | LongMatch::Ambiguous = lookup.find_long(normalization, name) {
+            return ArgTypeBasic::LongOption(optset_string);
+        }
+    }
+    match is_short(first) {
+        true => ArgTypeBasic::ShortOptionSet(optset_string),
+        false => ArgTypeBasic::LongOption(optset_string),
+    }
+}
+
+/// Checks whether a *short option* set contains an unknown option
+///
+/// For the non-negated form, chars following one that takes data are data, and so not checked.
+fn has_unknown_short<'r, 'a, L>(lookup: &L, optset_string: &str, negated: bool) -> bool
+    where L: Lookup<'r, 'a> + ?Sized, 'a: 'r
+{
+    for ch in optset_string.chars() {
+        match lookup.find_short(ch) {
+            None => { return true; },
+            Some(matched) if matched.expects_data && !negated => { return false; },
+            Some(_) => {},
+        }
+    }
+    false
+}
+
+/// Checks whether an argument is a signed number (e.g. `-5`, `-3.14`, `-1e6`, `+2`)
+///
+/// Only decimal digits, a decimal point and an exponent are accepted (thus not `-inf` or `-nan`).
+fn is_signed_number(arg: &str) -> bool {
+    let number = match arg.as_bytes().first() {
+        Some(b'-') | Some(b'+') => &arg[1..],
+        _ => { return false; },
+    };
+    match number.chars().next() {
+        Some(ch) if ch.is_ascii_digit() || ch == '.' => {},
+        _ => { return false; },
+    }
+    number.chars().all(|ch| ch.is_ascii_digit() || ".eE+-".contains(ch))
+        && number.parse::<f64>().is_ok()
+}
+
+/// Splits a long option argument (without prefix) into name and optional “in-same-arg” data value
+fn split_long_data<'a>(opt_string: &'a str, separators: &[char]) -> (&'a str, Option<&'a str>) {
+    match opt_string.find(separators) {
+        None => (opt_string, None),
+        Some(i) => {
+            let split = opt_string.split_at(i);
+            // We know that the separator is definately there, and a custom syntax may use one that
+            // is encoded as more than one byte, so we skip it by its encoded length.
+            let separator_len = split.1.chars().next().map_or(0, char::len_utf8);
+            let data_included = unsafe { split.1.get_unchecked(separator_len..) };
+            (split.0, Some(data_included))
+        },
+    }
+}
+
+/// Finds the long option matching `name`, which may be an abbreviation (if allowed)
+///
+/// Names are compared in normalized form (a no-op unless normalization is enabled).
+fn find_long<'o, 'r, 'a>(options: &'o OptionSet<'r, 'a>, normalization: &Normalization, name: &str)
+    -> LongMatch<'r, 'a>
+    where 'r: 'o, 'a: 'r
+{
+    let name = normalization.apply(name);
+
+    let mut matched: Option<&LongOption> = None;
+    let mut ambiguity = false;
+    for candidate in options.long {
+        let candidate_name = normalization.apply(candidate.name);
+        // Exact
+        if candidate_name == name {
+            // An exact match overrules a previously found partial match and ambiguity found with
+            // multiple partial matches.
+            return LongMatch::Found(candidate);
+        }
+        // Abbreviated
+        else if options.allow_abbreviations && candidate.allow_abbreviation && !ambiguity
+            && name.len() < candidate_name.len()
+            && name.chars().count() >= candidate.min_abbreviation
+            && candidate_name.starts_with(&*name)
+        {
+            match matched {
+                Some(_) => { ambiguity = true; },
+                None => { matched = Some(candidate); },
+            }
+        }
+    }
+    match (ambiguity, matched) {
+        (true, _) => LongMatch::Ambiguous,
+        (false, Some(matched)) => LongMatch::Found(matched),
+        (false, None) => LongMatch::None,
+    }
+}
+
+/// Adds a late option warning, before the items of the argument concerned, if it produced any
+fn note_late(results: &mut Analysis, late: Option<(usize, usize)>) {
+    if let Some((arg_index, position)) = late {
+        if results.items.len() > position {
+            results.items.insert(position, ItemClass::Warn(ItemW::LateOption(arg_index)));
+            results.warn = true;
+        }
+    }
+}
+
+/// Adds a warning following the item(s) for a matched *long option*, if matched by an abbreviated
+/// `name` and such warnings are enabled
+fn note_abbreviated<'a>(results: &mut Analysis<'a>, options: &OptionSet,
+    normalization: &Normalization, arg_index: usize, option: &LongOption<'a>, name: &str)
+{
+    if options.warn_abbreviations && is_abbreviation(normalization, option, name) {
+        results.add(ItemClass::Warn(ItemW::LongAbbreviated(arg_index, option.name)));
+        results.warn = true;
+    }
+}
+
+/// Adds a warning following the item(s) for a matched *long option*, if deprecated
+pub(crate) fn note_deprecated_long<'a>(results: &mut Analysis<'a>, arg_index: usize,
+    option: &LongOption<'a>)
+{
+    if let OptionStatus::Deprecated(replacement) = option.status {
+        results.add(ItemClass::Warn(ItemW::LongDeprecated(arg_index, option.name, replacement)));
+        results.warn = true;
+    }
+}
+
+/// Adds a warning following the item for a matched *short option*, if deprecated
+fn note_deprecated_short(results: &mut Analysis, arg_index: usize, option: &ShortOption) {
+    if let OptionStatus::Deprecated(replacement) = option.status {
+        results.add(ItemClass::Warn(ItemW::ShortDeprecated(arg_index, option.ch, replacement)));
+        results.warn = true;
+    }
+}
+
+/// Matches a data value against the possible values of `option`, giving the full value matched
+///
+/// Values are matched exactly, or as a unique abbreviation (if `abbreviations` is set), just as
+/// with long option names, including the option’s own `allow_abbreviation` and `min_abbreviation`
+/// controls (though without name normalization). Gives the value as is if the option does not
+/// restrict its values, and `None` if there is no match.
+pub(crate) fn match_value<'a>(option: &LongOption<'a>, value: &'a str, abbreviations: bool)
+    -> Option<&'a str>
+{
+    if option.possible_values.is_empty() {
+        return Some(value);
+    }
+    let abbreviations = abbreviations && option.allow_abbreviation && !value.is_empty()
+        && value.chars().count() >= option.min_abbreviation;
+    let mut matched = None;
+    let mut ambiguity = false;
+    for &candidate in option.possible_values {
+        // Exact
+        if candidate == value {
+            return Some(candidate);
+        }
+        // Abbreviated
+        else if abbreviations && !ambiguity && candidate.starts_with(value) {
+            match matched {
+                Some(_) => { ambiguity = true; },
+                None => { matched = Some(candidate); },
+            }
+        }
+    }
+    match ambiguity {
+        true => None,
+        false => matched,
     }
 }
 
-/// Assess argument type, returning options without their prefix, for “alternate” mode
-fn get_basic_arg_type_alternate<'a>(arg: &'a str) -> ArgTypeBasic<'a> {
-    if arg == EARLY_TERMINATOR {
-        ArgTypeBasic::EarlyTerminator
+/// Checks whether `name` matched `option` as an abbreviation
+fn is_abbreviation(normalization: &Normalization, option: &LongOption, name: &str) -> bool {
+    normalization.apply(option.name) != normalization.apply(name)
+}
+
+/// Handles the help alias (e.g. `/?`), and `+`/`-` polarity suffixes on flag type options, where
+/// supported by the syntax
+///
+/// The help alias is matched against an option with that name if available, otherwise `help`. A
+/// name with a polarity suffix is only taken as such if without the suffix it matches a flag type
+/// option. Returns the option along with whether negated and the name it was matched by (without
+/// any suffix, and being the option’s own name for the help alias, which is not an abbreviation),
+/// or `None` if not applicable.
+fn polarity<'r, 'a, 'n, L>(lookup: &L, syntax: &Syntax, normalization: &Normalization,
+    name: &'n str) -> Option<(&'r LongOption<'a>, bool, &'n str)>
+    where L: Lookup<'r, 'a> + ?Sized, 'a: 'r, 'a: 'n
+{
+    if Some(name) == syntax.help_alias {
+        let long = lookup.options().long;
+        return long.iter()
+            .find(|o| o.name == name)
+            .or_else(|| long.iter().find(|o| {
+                normalization.apply(o.name) == normalization.apply(HELP_OPTION)
+            }))
+            .filter(|o| !o.expects_data)
+            .map(|o| (o, false, o.name));
     }
-    else if has_prefix(arg, SINGLE_DASH_PREFIX) {
-        ArgTypeBasic::LongOption(unsafe { arg.get_unchecked(SINGLE_DASH_PREFIX.len()..) })
+    if !syntax.polarity_suffixes {
+        return None;
+    }
+    let (stripped, negated) = match name.as_bytes()[name.len() - 1] {
+        b'+' => (&name[..name.len() - 1], false),
+        b'-' => (&name[..name.len() - 1], true),
+        _ => { return None; },
+    };
+    if stripped.is_empty() {
+        return None;
     }
-    else {
-        ArgTypeBasic::NonOption
+    match lookup.find_long(normalization, stripped) {
+        LongMatch::Found(flag) if !flag.expects_data => {
+            Some((flag, negated, stripped))
+        },
+        _ => None,
     }
 }
@@ -0,0 +1,46 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Help output generation
+
+use super::options::*;
+
+/// Generates a usage synopsis line for the given option set
+pub(crate) fn synopsis(set: &OptionSet, prog_name: &str) -> String {
+    let mut line = String::from(prog_name);
+    if !set.is_empty() {
+        line.push_str(" [OPTIONS]");
+    }
+    for positional in set.positionals {
+        describe_positional(&mut line, positional);
+    }
+    line
+}
+
+/// Appends a description of a positional, per its arity, e.g. `INPUT`, `[INPUT]` or `[FILE]...`
+fn describe_positional(line: &mut String, positional: &Positional) {
+    let name = positional.name;
+    for _ in 0..positional.min {
+        line.push(' ');
+        line.push_str(name);
+    }
+    match positional.max {
+        // One or more: mark the last required instance as repeatable
+        None if positional.min > 0 => line.push_str("..."),
+        None => { line.push_str(" ["); line.push_str(name); line.push_str("]..."); },
+        Some(max) if max > positional.min => {
+            line.push_str(" ["); line.push_str(name); line.push(']');
+            if max - positional.min > 1 {
+                line.push_str("...");
+            }
+        },
+        Some(_) => {},
+    }
+}
@@ -24,12 +24,22 @@
 #![doc(html_logo_url = "https://github.com/jnqnfe/gong/raw/master/logo.png",
        html_favicon_url = "https://github.com/jnqnfe/gong/raw/master/favicon.ico")]
 
+#[cfg(feature = "unicode-normalization")]
+extern crate unicode_normalization;
+
 pub mod docs;
 #[macro_use]
 mod macros; //Note: If we use these in the lib (e.g. internal tests) then this mod must come first!
 pub mod analysis;
+pub mod argfiles;
+pub mod compiled;
+pub mod completion;
+pub mod config;
+pub mod diagnostics;
 mod engine;
+mod help;
 pub mod options;
+pub mod values;
 
 /* -- Deprecated stuff -- */
 /* Note, not possible to use a type for enum aliasing to mark deprecated, have to do without */
@@ -19,6 +19,9 @@
 ///
 /// The last two can be left off if both defaults.
 ///
+/// Any other attributes (such as `positionals`) can be given after a semi-colon, as a list of
+/// `field: value` pairs. Those not given take default values.
+///
 /// Example:
 ///
 /// ```rust
@@ -29,19 +32,24 @@
 /// let _ = gong_option_set!(vec![], vec![]);
 /// // With modes, empty option lists
 /// let _ = gong_option_set!(vec![], vec![], gong::options::OptionsMode::Standard, true);
+/// // With positionals
+/// let _ = gong_option_set!(vec![], vec![]; positionals: vec![ gong_positional!("INPUT") ]);
 /// # }
 /// ```
 #[macro_export]
 macro_rules! gong_option_set {
-    ( $long:expr, $short:expr, $mode:expr, $abbr:expr ) => {
+    ( $long:expr, $short:expr, $mode:expr, $abbr:expr $(; $($field:ident: $value:expr),+ )* ) => {
         $crate::options::OptionSetEx {
-            long: $long, short: $short, mode: $mode, allow_abbreviations: $abbr
+            long: $long, short: $short, mode: $mode, allow_abbreviations: $abbr,
+            $($($field: $value,)+)*
+            ..$crate::options::OptionSetEx::new(0, 0)
         }
     };
-    ( $long:expr, $short:expr ) => {
+    ( $long:expr, $short:expr $(; $($field:ident: $value:expr),+ )* ) => {
         $crate::options::OptionSetEx {
-            long: $long, short: $short, mode: $crate::options::OptionsMode::Standard,
-            allow_abbreviations: true
+            long: $long, short: $short,
+            $($($field: $value,)+)*
+            ..$crate::options::OptionSetEx::new(0, 0)
         }
     };
     () => { gong_option_set!(vec![], vec![]) };
@@ -58,6 +66,9 @@ macro_rules! gong_option_set {
 ///
 /// The last two can be left off if both defaults.
 ///
+/// Any other attributes (such as `positionals`) can be given after a semi-colon, as a list of
+/// `field: value` pairs. Those not given take default values.
+///
 /// Example:
 ///
 /// ```rust
@@ -68,36 +79,99 @@ macro_rules! gong_option_set {
 /// let _ = gong_option_set_fixed!([], []);
 /// // With modes, empty option lists
 /// let _ = gong_option_set_fixed!([], [], gong::options::OptionsMode::Standard, true);
+/// // With positionals
+/// let _ = gong_option_set_fixed!([], []; positionals: &[ gong_positional!("INPUT") ]);
 /// # }
 /// ```
 #[macro_export]
 macro_rules! gong_option_set_fixed {
-    ( $long:tt, $short:tt, $mode:expr, $abbr:expr ) => {
+    ( $long:tt, $short:tt, $mode:expr, $abbr:expr $(; $($field:ident: $value:expr),+ )* ) => {
         $crate::options::OptionSet {
-            long: &$long, short: &$short, mode: $mode, allow_abbreviations: $abbr
+            long: &$long, short: &$short, mode: $mode, allow_abbreviations: $abbr,
+            $($($field: $value,)+)*
+            ..$crate::options::OptionSet::EMPTY
         }
     };
-    ( $long:tt, $short:tt ) => {
+    ( $long:tt, $short:tt $(; $($field:ident: $value:expr),+ )* ) => {
         $crate::options::OptionSet {
-            long: &$long, short: &$short, mode: $crate::options::OptionsMode::Standard,
-            allow_abbreviations: true
+            long: &$long, short: &$short,
+            $($($field: $value,)+)*
+            ..$crate::options::OptionSet::EMPTY
         }
     };
     () => { gong_option_set_fixed!([], []) };
 }
 
+/// Construct an [`OptionSet`](options/struct.OptionSet.html), validated at compile time
+///
+/// Takes the same parameters as `gong_option_set_fixed!`, but the set is checked in constant
+/// evaluation (with [`OptionSet::assert_valid`]), such that an invalid set fails to compile. It
+/// must thus be `'static`, as in a `static` declaration.
+///
+/// Example:
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate gong;
+/// static OPTS: gong::options::OptionSet = gong_option_set_checked!(
+///     [ gong_longopt!("help"), gong_longopt!("foo") ],
+///     [ gong_shortopt!('h') ]
+/// );
+/// # fn main() {}
+/// ```
+///
+/// A duplicate option (for instance) is rejected:
+///
+/// ```rust,compile_fail
+/// # #[macro_use]
+/// # extern crate gong;
+/// static OPTS: gong::options::OptionSet = gong_option_set_checked!(
+///     [ gong_longopt!("foo"), gong_longopt!("foo") ],
+///     []
+/// );
+/// # fn main() {}
+/// ```
+///
+/// [`OptionSet::assert_valid`]: options/struct.OptionSet.html#method.assert_valid
+#[macro_export]
+macro_rules! gong_option_set_checked {
+    ( $($args:tt)* ) => {{
+        const SET: $crate::options::OptionSet<'static, 'static> =
+            $crate::options::OptionSet::assert_valid($crate::gong_option_set_fixed!($($args)*));
+        SET
+    }};
+}
+
 /// Construct a [`LongOption`](options/struct.LongOption.html)
 ///
 /// Takes:
 ///
 /// 1. Option name
 /// 2. Boolean indicating whether or not it takes a data arg (optional, defaults to false)
+///
+/// Any other attributes (such as `env`) can be given after a semi-colon, as a list of
+/// `field: value` pairs (this requires the second param). Those not given take default values.
+///
+/// Example:
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate gong;
+/// # fn main() {
+/// let _ = gong_longopt!("foo");
+/// let _ = gong_longopt!("token", true; env: Some("MYTOOL_TOKEN"));
+/// # }
+/// ```
 #[macro_export]
 macro_rules! gong_longopt {
-    ( $name:expr, $data:expr ) => {
-        $crate::options::LongOption { name: $name, expects_data: $data }
+    ( $name:expr, $data:expr $(; $($field:ident: $value:expr),+ )* ) => {
+        $crate::options::LongOption {
+            name: $name, expects_data: $data,
+            $($($field: $value,)+)*
+            ..$crate::options::LongOption::BASE
+        }
     };
-    ( $name:expr ) => { $crate::options::LongOption { name: $name, expects_data: false } };
+    ( $name:expr ) => { $crate::gong_longopt!($name, false) };
 }
 
 /// Construct a [`ShortOption`](options/struct.ShortOption.html)
@@ -106,8 +180,48 @@ macro_rules! gong_longopt {
 ///
 /// 1. Option char
 /// 2. Boolean indicating whether or not it takes a data arg (optional, defaults to false)
+///
+/// Any other attributes (such as `negatable`) can be given after a semi-colon, as a list of
+/// `field: value` pairs (this requires the second param). Those not given take default values.
+///
+/// Example:
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate gong;
+/// # fn main() {
+/// let _ = gong_shortopt!('x');
+/// let _ = gong_shortopt!('x', false; negatable: true);
+/// # }
+/// ```
 #[macro_export]
 macro_rules! gong_shortopt {
-    ( $ch:expr, $data:expr ) => { $crate::options::ShortOption { ch: $ch, expects_data: $data } };
-    ( $ch:expr ) => { $crate::options::ShortOption { ch: $ch, expects_data: false } };
+    ( $ch:expr, $data:expr $(; $($field:ident: $value:expr),+ )* ) => {
+        $crate::options::ShortOption {
+            ch: $ch, expects_data: $data,
+            $($($field: $value,)+)*
+            ..$crate::options::ShortOption::BASE
+        }
+    };
+    ( $ch:expr ) => { $crate::gong_shortopt!($ch, false) };
+}
+
+/// Construct a [`Positional`](options/struct.Positional.html)
+///
+/// Takes:
+///
+/// 1. Positional name
+/// 2. Minimum number of arguments (optional, defaults to one)
+/// 3. Maximum number of arguments, as an `Option` (optional, defaults to `Some` of the minimum, or
+///    of one if the minimum is zero)
+#[macro_export]
+macro_rules! gong_positional {
+    ( $name:expr, $min:expr, $max:expr ) => {
+        $crate::options::Positional { name: $name, min: $min, max: $max }
+    };
+    ( $name:expr, $min:expr ) => {{
+        let min: usize = $min;
+        $crate::options::Positional { name: $name, min, max: Some(if min > 1 { min } else { 1 }) }
+    }};
+    ( $name:expr ) => { $crate::options::Positional { name: $name, min: 1, max: Some(1) } };
 }
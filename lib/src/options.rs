@@ -10,7 +10,9 @@
 
 //! “Available” option sets
 
+use std::borrow::Cow;
 use std::convert::AsRef;
+use std::fmt;
 
 #[deprecated(since = "1.2.0", note = "Use either `OptionSet` or `OptionSetEx` now, as applicable")]
 pub type Options<'a> = OptionSetEx<'a>;
@@ -19,6 +21,18 @@ pub type Options<'a> = OptionSetEx<'a>;
 pub(crate) const ABBR_SUP_DEFAULT: bool = true;
 /// Default mode
 pub(crate) const MODE_DEFAULT: OptionsMode = OptionsMode::Standard;
+/// Default ordering policy
+pub(crate) const ORDERING_DEFAULT: OrderingPolicy = OrderingPolicy::Intermixed;
+/// Default name normalization policy
+pub(crate) const NORMALIZATION_DEFAULT: Normalization = Normalization::NONE;
+/// Default plus prefix support state
+pub(crate) const PLUS_PREFIX_DEFAULT: bool = false;
+/// Default negative number support state
+pub(crate) const NEGATIVE_NUMBERS_DEFAULT: bool = false;
+/// Default unknown option pass-through state
+pub(crate) const PASS_THROUGH_DEFAULT: bool = false;
+/// Default abbreviation use warning state
+pub(crate) const WARN_ABBREVIATIONS_DEFAULT: bool = false;
 
 /// Extendible option set
 ///
@@ -31,8 +45,15 @@ pub struct OptionSetEx<'a> {
     /* NOTE: these have been left public to allow creation via macros */
     pub long: Vec<LongOption<'a>>,
     pub short: Vec<ShortOption>,
+    pub positionals: Vec<Positional<'a>>,
     pub mode: OptionsMode,
     pub allow_abbreviations: bool,
+    pub ordering: OrderingPolicy,
+    pub normalization: Normalization,
+    pub plus_prefix: bool,
+    pub negative_numbers: bool,
+    pub pass_through: bool,
+    pub warn_abbreviations: bool,
 }
 
 impl<'a> Default for OptionSetEx<'a> {
@@ -53,8 +74,15 @@ pub struct OptionSet<'r, 'a: 'r> {
     /* NOTE: these have been left public to allow efficient static creation of options */
     pub long: &'r [LongOption<'a>],
     pub short: &'r [ShortOption],
+    pub positionals: &'r [Positional<'a>],
     pub mode: OptionsMode,
     pub allow_abbreviations: bool,
+    pub ordering: OrderingPolicy,
+    pub normalization: Normalization,
+    pub plus_prefix: bool,
+    pub negative_numbers: bool,
+    pub pass_through: bool,
+    pub warn_abbreviations: bool,
 }
 
 impl<'r, 'a: 'r> PartialEq<OptionSet<'r, 'a>> for OptionSetEx<'a> {
@@ -77,6 +105,124 @@ pub enum OptionsMode {
     Standard,
     /// Alternate: Long options only, with single dash prefix.
     Alternate,
+    /// DOS: Long options only, with forward slash prefix (`/help`), in the style of DOS/Windows
+    /// programs. Data values can be given in the same argument after either a colon or equals
+    /// (`/o:file`, `/o=file`), or in the next argument. Names are matched case-insensitively
+    /// (ASCII case folding at minimum). Flag type options may carry a `+` or `-` suffix
+    /// (`/flag+`, `/flag-`), the latter giving a negated item. `/?` is taken as a request for the
+    /// option named `?`, or otherwise `help`.
+    ///
+    /// Note, with this mode, absolute Unix-style paths (`/usr/bin`) look like options.
+    Dos,
+    /// Long-only: As standard, but a single dash prefixed argument (`-foo`) is first tried as a
+    /// long option, falling back to a short option set where no long option matches, in the
+    /// manner of glibc’s `getopt_long_only`. See the options documentation for details.
+    LongOnly,
+    /// Custom: As described by the given [`Syntax`](struct.Syntax.html).
+    Custom(&'static Syntax),
+}
+
+impl OptionsMode {
+    /// Gives the argument syntax of the mode
+    pub const fn syntax(&self) -> &'static Syntax {
+        match *self {
+            OptionsMode::Standard => &Syntax::STANDARD,
+            OptionsMode::Alternate => &Syntax::ALTERNATE,
+            OptionsMode::Dos => &Syntax::DOS,
+            OptionsMode::LongOnly => &Syntax::LONG_ONLY,
+            OptionsMode::Custom(syntax) => syntax,
+        }
+    }
+}
+
+/// Description of an argument syntax, used in classifying arguments
+///
+/// Predefined instances are provided for each of the built-in modes. Others can be used with
+/// [`OptionsMode::Custom`](enum.OptionsMode.html#variant.Custom), for unusual conventions.
+///
+/// An argument exactly matching the *early terminator* is taken as such. Otherwise, arguments
+/// starting with (and longer than) a *long option* prefix are *long options*, those starting with
+/// the *short option* prefix are *short option* sets, and all others are *non-options*, with
+/// prefixes checked in that order. The same then goes for the negated prefixes (if plus prefix
+/// support is enabled in the option set).
+///
+/// Note, validation only checks *long option* names for `=`, names should also not contain any
+/// other data separator of a custom syntax.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Syntax {
+    /// *Long option* prefixes, checked in order (thus if one prefix is the start of another, the
+    /// longer must be listed first)
+    pub long_prefixes: &'static [&'static str],
+    /// *Short option* prefix, if *short options* are supported
+    pub short_prefix: Option<&'static str>,
+    /// *Early terminator*, if supported
+    pub early_terminator: Option<&'static str>,
+    /// Characters that separate a *long option* name from an in-same-argument *data value* (the
+    /// first found is used)
+    pub data_separators: &'static [char],
+    /// Negated *long option* prefix (used only with plus prefix support enabled in the set)
+    pub negated_long_prefix: Option<&'static str>,
+    /// Negated *short option* prefix (used only with plus prefix support enabled in the set)
+    pub negated_short_prefix: Option<&'static str>,
+    /// Whether *long option* names are matched case-insensitively (ASCII case folding, at minimum)
+    pub case_insensitive: bool,
+    /// Whether flag type *long options* may have a `+` or `-` suffix (the latter negating it)
+    pub polarity_suffixes: bool,
+    /// Name (e.g. `?`) to take as a request for the `help` option, where no option has that name
+    pub help_alias: Option<&'static str>,
+    /// Whether *short option* set arguments are first tried as a *long option*, falling back to a
+    /// *short option* set if no *long option* matches (as with glibc’s `getopt_long_only`)
+    pub long_first: bool,
+}
+
+impl Syntax {
+    /// Standard mode syntax
+    pub const STANDARD: Syntax = Syntax {
+        long_prefixes: &["--"],
+        short_prefix: Some("-"),
+        early_terminator: Some("--"),
+        data_separators: &['='],
+        negated_long_prefix: Some("++"),
+        negated_short_prefix: Some("+"),
+        case_insensitive: false,
+        polarity_suffixes: false,
+        help_alias: None,
+        long_first: false,
+    };
+
+    /// Alternate mode syntax
+    pub const ALTERNATE: Syntax = Syntax {
+        long_prefixes: &["-"],
+        short_prefix: None,
+        early_terminator: Some("--"),
+        data_separators: &['='],
+        negated_long_prefix: Some("+"),
+        negated_short_prefix: None,
+        case_insensitive: false,
+        polarity_suffixes: false,
+        help_alias: None,
+        long_first: false,
+    };
+
+    /// DOS mode syntax
+    pub const DOS: Syntax = Syntax {
+        long_prefixes: &["/"],
+        short_prefix: None,
+        early_terminator: Some("--"),
+        data_separators: &[':', '='],
+        negated_long_prefix: None,
+        negated_short_prefix: None,
+        case_insensitive: true,
+        polarity_suffixes: true,
+        help_alias: Some("?"),
+        long_first: false,
+    };
+
+    /// Long-only mode syntax
+    pub const LONG_ONLY: Syntax = Syntax {
+        long_first: true,
+        ..Syntax::STANDARD
+    };
 }
 
 impl Default for OptionsMode {
@@ -85,6 +231,131 @@ impl Default for OptionsMode {
     }
 }
 
+/// Used to assert how options and *non-options* may be ordered relative to each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderingPolicy {
+    /// Intermixed (default): Options may be given anywhere, before, between or after *non-options*.
+    Intermixed,
+    /// Stop at non-option: Once the given number of *non-options* have been encountered, all
+    /// remaining arguments are taken to be *non-options*, as with an early terminator. With a value
+    /// of one, this gives the POSIX behaviour of stopping at the first *non-option*, as needed by
+    /// programs wrapping the execution of another (like `env` or `nice`). With zero, no arguments
+    /// are taken to be options.
+    StopAtNonOption(usize),
+    /// Warn on late options: Options may be given anywhere, but those given after a *non-option*
+    /// are accompanied by a [`LateOption`](../analysis/enum.ItemW.html#variant.LateOption)
+    /// warning.
+    WarnLateOptions,
+}
+
+impl Default for OrderingPolicy {
+    fn default() -> Self {
+        ORDERING_DEFAULT
+    }
+}
+
+/// Long option name normalization policy
+///
+/// This controls the differences in form ignored in matching *long option* names, both in
+/// processing arguments, and in checking for duplicates in validation. Short options are not
+/// affected. Names reported in analysis items are always the declared form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalization {
+    /// Unicode normalization form
+    pub unicode: UnicodeForm,
+    /// Case folding
+    pub case: CaseFolding,
+    /// Whether to treat dashes (`-`) and underscores (`_`) as equal
+    pub fold_separators: bool,
+}
+
+/// Unicode normalization form, for [`Normalization`](struct.Normalization.html)
+///
+/// Normalization forms other than `None` require the `unicode-normalization` crate feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnicodeForm {
+    /// None: names in different forms (e.g. precomposed `ö` versus `o` followed by a combining
+    /// diaeresis) are different names
+    None,
+    /// NFC: canonically equivalent names are matched
+    #[cfg(feature = "unicode-normalization")]
+    Nfc,
+    /// NFKC: compatibility equivalent names are matched (e.g. `ﬁ` ligature versus `fi`)
+    #[cfg(feature = "unicode-normalization")]
+    Nfkc,
+}
+
+/// Case folding type, for [`Normalization`](struct.Normalization.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseFolding {
+    /// None: matching is case-sensitive
+    None,
+    /// ASCII: ASCII letters are matched case-insensitively
+    Ascii,
+    /// Unicode: all letters are matched case-insensitively (per Unicode lower-case mapping)
+    Unicode,
+}
+
+impl Normalization {
+    /// No normalization (the default); names must match exactly
+    pub const NONE: Normalization = Normalization {
+        unicode: UnicodeForm::None,
+        case: CaseFolding::None,
+        fold_separators: false,
+    };
+
+    /// Checks whether any normalization is applied
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        *self == Self::NONE
+    }
+
+    /// Gives the normalized form of a name
+    pub fn apply<'s>(&self, name: &'s str) -> Cow<'s, str> {
+        if self.is_none() {
+            return Cow::Borrowed(name);
+        }
+        let name = normalize_unicode(name, self.unicode);
+        let mut normalized = match self.case {
+            CaseFolding::None => name.into_owned(),
+            CaseFolding::Ascii => name.to_ascii_lowercase(),
+            CaseFolding::Unicode => name.to_lowercase(),
+        };
+        if self.fold_separators && normalized.contains('_') {
+            normalized = normalized.replace('_', "-");
+        }
+        Cow::Owned(normalized)
+    }
+}
+
+impl Default for Normalization {
+    fn default() -> Self {
+        NORMALIZATION_DEFAULT
+    }
+}
+
+/// Applies Unicode normalization
+#[cfg(feature = "unicode-normalization")]
+fn normalize_unicode(name: &str, form: UnicodeForm) -> Cow<'_, str> {
+    use unicode_normalization::UnicodeNormalization;
+    // ASCII is unaffected by all forms
+    if name.is_ascii() {
+        return Cow::Borrowed(name);
+    }
+    match form {
+        UnicodeForm::None => Cow::Borrowed(name),
+        UnicodeForm::Nfc => Cow::Owned(name.nfc().collect()),
+        UnicodeForm::Nfkc => Cow::Owned(name.nfkc().collect()),
+    }
+}
+
+/// Applies Unicode normalization (only `None` is available without the feature)
+#[cfg(not(feature = "unicode-normalization"))]
+#[inline(always)]
+fn normalize_unicode(name: &str, _form: UnicodeForm) -> Cow<'_, str> {
+    Cow::Borrowed(name)
+}
+
 /// Description of an available long option
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LongOption<'a> {
@@ -93,6 +364,22 @@ pub struct LongOption<'a> {
     pub name: &'a str,
     /// Whether option expects a data argument
     pub expects_data: bool,
+    /// Environment variable to fall back on when not given on the command line
+    pub env: Option<&'a str>,
+    /// Whether the option accepts the plus prefixed (negated) form (flag type options only)
+    pub negatable: bool,
+    /// Possible values of the data argument (data taking options only, empty for unrestricted)
+    pub possible_values: &'a [&'a str],
+    /// Default value of the data argument, for when not given (data taking options only)
+    pub default: Option<&'a str>,
+    /// Status, for retiring options
+    pub status: OptionStatus<'a>,
+    /// Whether the name, and any possible value, may be abbreviated (where abbreviations are
+    /// allowed in the set)
+    pub allow_abbreviation: bool,
+    /// Minimum length (in `char`s) of an abbreviation of the name, or of any possible value (zero
+    /// for no minimum)
+    pub min_abbreviation: usize,
 }
 
 /// Description of an available short option
@@ -103,11 +390,63 @@ pub struct ShortOption {
     pub ch: char,
     /// Whether option expects a data argument
     pub expects_data: bool,
+    /// Whether the option accepts the plus prefixed (negated) form (flag type options only)
+    pub negatable: bool,
+    /// Status, for retiring options
+    pub status: OptionStatus<'static>,
+}
+
+/// Status of an option, for retiring options gracefully
+///
+/// Options of all statuses are matched as usual, differing only in what is reported and where they
+/// are shown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OptionStatus<'a> {
+    /// In normal use
+    #[default]
+    Normal,
+    /// Matched as usual, but excluded from help output and completion
+    Hidden,
+    /// Matched as usual, but with a warning, naming the replacement, if any, as to be shown to the
+    /// user (e.g. `--colour`). Still included in help output and completion, since still in use.
+    Deprecated(Option<&'a str>),
+    /// Legacy option accepted (along with any *data value*) for compatibility only, but otherwise
+    /// ignored. No items are given for it. Excluded from help output and completion.
+    Legacy,
+}
+
+impl<'a> OptionStatus<'a> {
+    /// Whether an option of this status should be excluded from help output and completion
+    ///
+    /// This is so for `Hidden` and `Legacy` options only; deprecated options are still accepted
+    /// (with a warning), thus remain visible.
+    pub fn is_hidden(&self) -> bool {
+        match *self {
+            OptionStatus::Hidden | OptionStatus::Legacy => true,
+            OptionStatus::Normal | OptionStatus::Deprecated(_) => false,
+        }
+    }
+}
+
+/// Description of an available positional argument
+///
+/// Positionals are bound, in order of declaration, to the *non-options* found in an analysis. Each
+/// takes at least `min` and at most `max` of them, with `None` for `max` meaning unlimited (only
+/// permitted for the last positional).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Positional<'a> {
+    /* NOTE: these have been left public to allow efficient static creation of positionals */
+    /// Name, as used in the usage synopsis and in binding results
+    pub name: &'a str,
+    /// Minimum number of arguments
+    pub min: usize,
+    /// Maximum number of arguments (`None` for unlimited)
+    pub max: Option<usize>,
 }
 
 /// Description of a validation issue within an option in an [`OptionSet`](struct.OptionSet.html) or
 /// [`OptionSetEx`](struct.OptionSetEx.html) set.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OptionFlaw<'a> {
     /// Long option name is empty string
     LongEmpty,
@@ -119,6 +458,140 @@ pub enum OptionFlaw<'a> {
     ShortDup(char),
     /// Duplicate long option found
     LongDup(&'a str),
+    /// Long option has a default value, but does not take data, or the value is not one of its
+    /// possible values
+    LongDefaultInvalid(&'a str),
+    /// Positional name is empty string
+    PositionalEmpty,
+    /// Duplicate positional found
+    PositionalDup(&'a str),
+    /// Positional has a `max` lower than its `min`
+    PositionalMaxBelowMin(&'a str),
+    /// Positional with unlimited `max` is not the last one
+    PositionalUnboundedNotLast(&'a str),
+}
+
+/// A finding of a validation report (see `OptionSet::validation_report`)
+///
+/// This covers both hard errors ([`OptionFlaw`](enum.OptionFlaw.html)s) and lints (questionable,
+/// but valid, attributes), along with the option concerned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationFinding<'a> {
+    /// The option (or positional) concerned
+    pub location: FindingLocation<'a>,
+    /// What was found
+    pub kind: FindingKind<'a>,
+}
+
+/// Location of a [`ValidationFinding`](struct.ValidationFinding.html)
+///
+/// Gives the index of the entry within its list, along with its name or `char`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FindingLocation<'a> {
+    /// Long option
+    Long(usize, &'a str),
+    /// Short option
+    Short(usize, char),
+    /// Positional
+    Positional(usize, &'a str),
+}
+
+/// Type of [`ValidationFinding`](struct.ValidationFinding.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FindingKind<'a> {
+    /// A problem making the set invalid
+    Flaw(OptionFlaw<'a>),
+    /// A questionable attribute, which does not make the set invalid
+    Lint(OptionLint),
+}
+
+/// Severity of a [`ValidationFinding`](struct.ValidationFinding.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// Makes the set invalid
+    Error,
+    /// Questionable, but valid
+    Lint,
+}
+
+/// Description of a questionable (but valid) attribute of an option
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionLint {
+    /// Long option name begins with a dash (the prefix is not part of the name)
+    LeadingDash,
+    /// Name includes whitespace
+    Whitespace,
+    /// Name includes a control character
+    ControlChar,
+    /// Name includes an invisible (zero-width) character
+    ZeroWidth(char),
+    /// Name includes a character easily confused with the given ASCII character (e.g. Cyrillic
+    /// `а` versus Latin `a`)
+    Confusable(char, char),
+    /// Short option declared with a mode that does not support short options (it can never match)
+    ShortUnsupported,
+    /// Short option is a digit, with negative numbers taken as *non-options*, thus it cannot be
+    /// used on its own, or first in a set (`-1`, `-1x`), though it still matches later in a set
+    /// (`-x1`)
+    DigitWithNegativeNumbers,
+}
+
+impl<'a> ValidationFinding<'a> {
+    /// Gives the severity
+    pub fn severity(&self) -> Severity {
+        match self.kind {
+            FindingKind::Flaw(_) => Severity::Error,
+            FindingKind::Lint(_) => Severity::Lint,
+        }
+    }
+}
+
+impl fmt::Display for ValidationFinding<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let severity = match self.severity() {
+            Severity::Error => "error",
+            Severity::Lint => "lint",
+        };
+        match self.location {
+            FindingLocation::Long(i, "") => write!(f, "{}: long option (index {}): ", severity, i)?,
+            FindingLocation::Long(i, name) => write!(f, "{}: long option `{}` (index {}): ",
+                severity, name.escape_debug(), i)?,
+            FindingLocation::Short(i, ch) => write!(f, "{}: short option `{}` (index {}): ",
+                severity, ch.escape_debug(), i)?,
+            FindingLocation::Positional(i, "") => write!(f, "{}: positional (index {}): ",
+                severity, i)?,
+            FindingLocation::Positional(i, name) => write!(f, "{}: positional `{}` (index {}): ",
+                severity, name.escape_debug(), i)?,
+        }
+        match self.kind {
+            FindingKind::Flaw(ref flaw) => f.write_str(match *flaw {
+                OptionFlaw::LongEmpty |
+                OptionFlaw::PositionalEmpty => "name is empty",
+                OptionFlaw::LongIncludesEquals(_) => "name contains `=`",
+                OptionFlaw::ShortDash => "dash (`-`) is not a valid short option",
+                OptionFlaw::ShortDup(_) |
+                OptionFlaw::LongDup(_) |
+                OptionFlaw::PositionalDup(_) => "duplicate",
+                OptionFlaw::LongDefaultInvalid(_) =>
+                    "default value requires data taking option, and one of any possible values",
+                OptionFlaw::PositionalMaxBelowMin(_) => "maximum is below minimum",
+                OptionFlaw::PositionalUnboundedNotLast(_) => "unlimited maximum, but not last",
+            }),
+            FindingKind::Lint(lint) => match lint {
+                OptionLint::LeadingDash => f.write_str("name begins with a dash"),
+                OptionLint::Whitespace => f.write_str("name contains whitespace"),
+                OptionLint::ControlChar => f.write_str("name contains a control character"),
+                OptionLint::ZeroWidth(ch) => write!(f,
+                    "name contains invisible character U+{:04X}", ch as u32),
+                OptionLint::Confusable(ch, ascii) => write!(f,
+                    "name contains `{}` (U+{:04X}), easily confused with `{}`", ch, ch as u32,
+                    ascii),
+                OptionLint::ShortUnsupported => f.write_str("short options are not used by mode"),
+                OptionLint::DigitWithNegativeNumbers =>
+                    f.write_str("digit conflicts with negative number support"),
+            },
+        }
+    }
 }
 
 impl<'a> OptionSetEx<'a> {
@@ -128,8 +601,15 @@ impl<'a> OptionSetEx<'a> {
         Self {
             long: Vec::with_capacity(count_long),
             short: Vec::with_capacity(count_short),
+            positionals: Vec::new(),
             mode: MODE_DEFAULT,
             allow_abbreviations: ABBR_SUP_DEFAULT,
+            ordering: ORDERING_DEFAULT,
+            normalization: NORMALIZATION_DEFAULT,
+            plus_prefix: PLUS_PREFIX_DEFAULT,
+            negative_numbers: NEGATIVE_NUMBERS_DEFAULT,
+            pass_through: PASS_THROUGH_DEFAULT,
+            warn_abbreviations: WARN_ABBREVIATIONS_DEFAULT,
         }
     }
 
@@ -138,8 +618,15 @@ impl<'a> OptionSetEx<'a> {
         OptionSet {
             long: &self.long[..],
             short: &self.short[..],
+            positionals: &self.positionals[..],
             mode: self.mode,
             allow_abbreviations: self.allow_abbreviations,
+            ordering: self.ordering,
+            normalization: self.normalization,
+            plus_prefix: self.plus_prefix,
+            negative_numbers: self.negative_numbers,
+            pass_through: self.pass_through,
+            warn_abbreviations: self.warn_abbreviations,
         }
     }
 
@@ -155,7 +642,45 @@ impl<'a> OptionSetEx<'a> {
         self
     }
 
+    /// Set ordering policy
+    pub fn set_ordering(&mut self, ordering: OrderingPolicy) -> &mut Self {
+        self.ordering = ordering;
+        self
+    }
+
+    /// Set long option name normalization policy
+    pub fn set_normalization(&mut self, normalization: Normalization) -> &mut Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Enable/disable plus prefixed (negated) options (`+x`, `++foo`)
+    pub fn set_plus_prefix(&mut self, allow: bool) -> &mut Self {
+        self.plus_prefix = allow;
+        self
+    }
+
+    /// Enable/disable taking arguments that are numbers (e.g. `-5`, `-3.14`) as *non-options*
+    pub fn set_negative_numbers(&mut self, allow: bool) -> &mut Self {
+        self.negative_numbers = allow;
+        self
+    }
+
+    /// Enable/disable passing through unknown options verbatim (for wrapper programs)
+    pub fn set_pass_through(&mut self, enable: bool) -> &mut Self {
+        self.pass_through = enable;
+        self
+    }
+
+    /// Enable/disable warning of the use of abbreviated *long option* names
+    pub fn set_warn_abbreviations(&mut self, enable: bool) -> &mut Self {
+        self.warn_abbreviations = enable;
+        self
+    }
+
     /// Checks if empty
+    ///
+    /// Note, positionals are not considered.
     pub fn is_empty(&self) -> bool {
         self.long.is_empty() && self.short.is_empty()
     }
@@ -192,6 +717,22 @@ impl<'a> OptionSetEx<'a> {
         self
     }
 
+    /// Add a long option that accepts the plus prefixed (negated) form
+    ///
+    /// Panics (debug only) on invalid name.
+    pub fn add_long_negatable(&mut self, name: &'a str) -> &mut Self {
+        self.long.push(LongOption { negatable: true, ..LongOption::new(name, false) });
+        self
+    }
+
+    /// Add a short option that accepts the plus prefixed (negated) form
+    ///
+    /// Panics (debug only) on invalid `char` choice.
+    pub fn add_short_negatable(&mut self, ch: char) -> &mut Self {
+        self.short.push(ShortOption { negatable: true, ..ShortOption::new(ch, false) });
+        self
+    }
+
     /// Add an existing (ready-made) long option
     pub fn add_existing_long(&mut self, long: LongOption<'a>) -> &mut Self {
         self.long.push(long);
@@ -204,6 +745,36 @@ impl<'a> OptionSetEx<'a> {
         self
     }
 
+    /// Add a required positional, taking exactly one argument
+    ///
+    /// Panics (debug only) on invalid name.
+    pub fn add_positional(&mut self, name: &'a str) -> &mut Self {
+        self.positionals.push(Positional::new(name, 1, Some(1)));
+        self
+    }
+
+    /// Add an optional positional, taking at most one argument
+    ///
+    /// Panics (debug only) on invalid name.
+    pub fn add_optional_positional(&mut self, name: &'a str) -> &mut Self {
+        self.positionals.push(Positional::new(name, 0, Some(1)));
+        self
+    }
+
+    /// Add a trailing variadic positional, taking at least `min` arguments, with no upper limit
+    ///
+    /// Panics (debug only) on invalid name.
+    pub fn add_variadic_positional(&mut self, name: &'a str, min: usize) -> &mut Self {
+        self.positionals.push(Positional::new(name, min, None));
+        self
+    }
+
+    /// Add an existing (ready-made) positional
+    pub fn add_existing_positional(&mut self, positional: Positional<'a>) -> &mut Self {
+        self.positionals.push(positional);
+        self
+    }
+
     /// Checks validity of option set
     ///
     /// Returns `true` if valid.
@@ -220,6 +791,14 @@ impl<'a> OptionSetEx<'a> {
         validation::validate_set(&self.as_fixed(), true)
     }
 
+    /// Gives a validation report, covering both problems and lints, with locations
+    ///
+    /// See the same method on [`OptionSet`](struct.OptionSet.html#method.validation_report).
+    #[inline(always)]
+    pub fn validation_report(&self) -> Vec<ValidationFinding<'a>> {
+        validation::report(&self.as_fixed())
+    }
+
     /// Analyses provided program arguments.
     ///
     /// Returns a result set describing the result of the analysis. This may include `&str`
@@ -232,18 +811,51 @@ impl<'a> OptionSetEx<'a> {
     {
         super::engine::process(args, &self.as_fixed())
     }
+
+    /// Generates a usage synopsis line, such as `prog [OPTIONS] INPUT [FILE]...`
+    ///
+    /// Positionals are described per their declared arity.
+    pub fn synopsis(&self, prog_name: &str) -> String {
+        super::help::synopsis(&self.as_fixed(), prog_name)
+    }
 }
 
 impl<'r, 'a: 'r> OptionSet<'r, 'a> {
+    /// An empty set, with default settings
+    ///
+    /// Useful as the base for struct update syntax in `static` declarations, where `Default` cannot
+    /// be used.
+    pub const EMPTY: OptionSet<'r, 'a> = OptionSet {
+        long: &[],
+        short: &[],
+        positionals: &[],
+        mode: MODE_DEFAULT,
+        allow_abbreviations: ABBR_SUP_DEFAULT,
+        ordering: ORDERING_DEFAULT,
+        normalization: NORMALIZATION_DEFAULT,
+        plus_prefix: PLUS_PREFIX_DEFAULT,
+        negative_numbers: NEGATIVE_NUMBERS_DEFAULT,
+        pass_through: PASS_THROUGH_DEFAULT,
+        warn_abbreviations: WARN_ABBREVIATIONS_DEFAULT,
+    };
+
     /// Creates an “extendible” copy of `self`
     ///
     /// This duplicates the options in `self` into an [`OptionSetEx`](struct.OptionSetEx.html).
+    #[allow(clippy::iter_cloned_collect)]
     pub fn to_extendible(&self) -> OptionSetEx<'a> {
         OptionSetEx {
             long: self.long.iter().cloned().collect(),
             short: self.short.iter().cloned().collect(),
+            positionals: self.positionals.iter().cloned().collect(),
             mode: self.mode,
             allow_abbreviations: self.allow_abbreviations,
+            ordering: self.ordering,
+            normalization: self.normalization,
+            plus_prefix: self.plus_prefix,
+            negative_numbers: self.negative_numbers,
+            pass_through: self.pass_through,
+            warn_abbreviations: self.warn_abbreviations,
         }
     }
 
@@ -259,7 +871,54 @@ impl<'r, 'a: 'r> OptionSet<'r, 'a> {
         self
     }
 
+    /// Set ordering policy
+    pub fn set_ordering(&mut self, ordering: OrderingPolicy) -> &mut Self {
+        self.ordering = ordering;
+        self
+    }
+
+    /// Set long option name normalization policy
+    pub fn set_normalization(&mut self, normalization: Normalization) -> &mut Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Enable/disable plus prefixed (negated) options (`+x`, `++foo`)
+    pub fn set_plus_prefix(&mut self, allow: bool) -> &mut Self {
+        self.plus_prefix = allow;
+        self
+    }
+
+    /// Enable/disable taking arguments that are numbers (e.g. `-5`, `-3.14`) as *non-options*
+    pub fn set_negative_numbers(&mut self, allow: bool) -> &mut Self {
+        self.negative_numbers = allow;
+        self
+    }
+
+    /// Enable/disable passing through unknown options verbatim (for wrapper programs)
+    pub fn set_pass_through(&mut self, enable: bool) -> &mut Self {
+        self.pass_through = enable;
+        self
+    }
+
+    /// Enable/disable warning of the use of abbreviated *long option* names
+    pub fn set_warn_abbreviations(&mut self, enable: bool) -> &mut Self {
+        self.warn_abbreviations = enable;
+        self
+    }
+
+    /// Gives the normalization policy in effect, accounting for the mode
+    pub(crate) fn name_normalization(&self) -> Normalization {
+        let mut normalization = self.normalization;
+        if self.mode.syntax().case_insensitive && normalization.case == CaseFolding::None {
+            normalization.case = CaseFolding::Ascii;
+        }
+        normalization
+    }
+
     /// Checks if empty
+    ///
+    /// Note, positionals are not considered.
     pub fn is_empty(&self) -> bool {
         self.long.is_empty() && self.short.is_empty()
     }
@@ -280,6 +939,43 @@ impl<'r, 'a: 'r> OptionSet<'r, 'a> {
         validation::validate_set(self, true)
     }
 
+    /// Gives a validation report, covering both problems and lints, with locations
+    ///
+    /// The problems (of `Error` severity) are those given by [`validate`](#method.validate), in
+    /// the same order, but with the option concerned identified, including for those, such as
+    /// `LongEmpty`, that do not otherwise say. Lints (of `Lint` severity) are attributes that are
+    /// valid, but likely mistakes, such as a name including whitespace or an invisible character,
+    /// or a *short option* declared with a mode that does not use them. Lints for an option
+    /// follow any problems found with it in its list. An empty report means no issues at all.
+    pub fn validation_report(&self) -> Vec<ValidationFinding<'a>> {
+        validation::report(self)
+    }
+
+    /// Checks validity of option set, giving the first problem found, if any
+    ///
+    /// Being a `const fn`, this can be used in constant evaluation, and thus to reject an invalid
+    /// `static` set at compile time (see [`assert_valid`](#method.assert_valid)). Problems are
+    /// checked for in the same order as with [`validate`](#method.validate), thus the flaw given
+    /// is the first it would list, except that *long option* names are compared for duplicates
+    /// with only ASCII case folding and separator folding applied (and case folding only without a
+    /// Unicode normalization form), thus duplicates only found with fuller normalization are left
+    /// to `validate`.
+    pub const fn first_flaw(&self) -> Option<OptionFlaw<'a>> {
+        validation::first_flaw(self)
+    }
+
+    /// Gives back `self` if valid, otherwise panics, naming the problem
+    ///
+    /// This is intended for constant evaluation, where the panic becomes a compile error. See
+    /// [`first_flaw`](#method.first_flaw) for the checks done, and the
+    /// [`gong_option_set_checked`](../macro.gong_option_set_checked.html) macro.
+    pub const fn assert_valid(self) -> Self {
+        if let Some(flaw) = validation::first_flaw(&self) {
+            panic!("{}", validation::describe_flaw(&flaw));
+        }
+        self
+    }
+
     /// Analyses provided program arguments.
     ///
     /// Returns a result set describing the result of the analysis. This may include `&str`
@@ -292,32 +988,105 @@ impl<'r, 'a: 'r> OptionSet<'r, 'a> {
     {
         super::engine::process(args, self)
     }
+
+    /// Generates a usage synopsis line, such as `prog [OPTIONS] INPUT [FILE]...`
+    ///
+    /// Positionals are described per their declared arity.
+    pub fn synopsis(&self, prog_name: &str) -> String {
+        super::help::synopsis(self, prog_name)
+    }
 }
 
 impl<'a> LongOption<'a> {
+    /// A blank long option descriptor, with all attributes at their defaults
+    ///
+    /// Useful as the base for struct update syntax in `static` declarations. (Note that the name is
+    /// empty and must be replaced).
+    pub const BASE: LongOption<'a> = LongOption {
+        name: "",
+        expects_data: false,
+        env: None,
+        negatable: false,
+        possible_values: &[],
+        default: None,
+        status: OptionStatus::Normal,
+        allow_abbreviation: true,
+        min_abbreviation: 0,
+    };
+
     /// Create a new long option descriptor
     ///
     /// Panics (debug only) if the given name contains an `=` or is an empty string.
     fn new(name: &'a str, expects_data: bool) -> Self {
         debug_assert!(!name.is_empty(), "Long option name cannot be an empty string!");
         debug_assert!(!name.contains('='), "Long option name cannot contain ‘=’!");
-        Self { name, expects_data, }
+        Self { name, expects_data, ..Self::BASE }
     }
 }
 
 impl ShortOption {
+    /// A blank short option descriptor, with all attributes at their defaults
+    ///
+    /// Useful as the base for struct update syntax in `static` declarations. (Note that the `char`
+    /// is a null placeholder and must be replaced).
+    pub const BASE: ShortOption = ShortOption {
+        ch: '\0',
+        expects_data: false,
+        negatable: false,
+        status: OptionStatus::Normal,
+    };
+
     /// Create a new short option descriptor
     ///
     /// Panics (debug only) if the given char is `-`.
     fn new(ch: char, expects_data: bool) -> Self {
         debug_assert_ne!('-', ch, "Dash (‘-’) is not a valid short option!");
-        Self { ch, expects_data, }
+        Self { ch, expects_data, ..Self::BASE }
+    }
+}
+
+impl<'a> Positional<'a> {
+    /// Create a new positional descriptor
+    ///
+    /// Panics (debug only) if the given name is an empty string.
+    fn new(name: &'a str, min: usize, max: Option<usize>) -> Self {
+        debug_assert!(!name.is_empty(), "Positional name cannot be an empty string!");
+        Self { name, min, max, }
     }
 }
 
 /// Option set validation
 mod validation {
-    use super::{OptionSet, OptionFlaw};
+    use std::borrow::Cow;
+    use super::{OptionSet, OptionFlaw, CaseFolding, UnicodeForm};
+    use super::{ValidationFinding, FindingLocation, FindingKind, OptionLint};
+
+    /// Invisible (zero-width) characters
+    const ZERO_WIDTH: &[char] = &[
+        '\u{00ad}', // Soft hyphen
+        '\u{200b}', // Zero width space
+        '\u{200c}', // Zero width non-joiner
+        '\u{200d}', // Zero width joiner
+        '\u{2060}', // Word joiner
+        '\u{feff}', // Zero width no-break space (byte order mark)
+    ];
+
+    /// Characters easily confused with ASCII characters, along with the ASCII character
+    ///
+    /// This covers common Cyrillic and Greek look-alikes, and dashes (fullwidth forms are handled
+    /// separately).
+    const CONFUSABLES: &[(char, char)] = &[
+        ('а', 'a'), ('е', 'e'), ('о', 'o'), ('р', 'p'), ('с', 'c'), ('у', 'y'), ('х', 'x'),
+        ('ѕ', 's'), ('і', 'i'), ('ј', 'j'), ('ԁ', 'd'), ('ԛ', 'q'), ('ԝ', 'w'), ('һ', 'h'),
+        ('ӏ', 'l'),
+        ('А', 'A'), ('В', 'B'), ('Е', 'E'), ('К', 'K'), ('М', 'M'), ('Н', 'H'), ('О', 'O'),
+        ('Р', 'P'), ('С', 'C'), ('Т', 'T'), ('Х', 'X'), ('Ѕ', 'S'), ('І', 'I'), ('Ј', 'J'),
+        ('ο', 'o'), ('ν', 'v'), ('Α', 'A'), ('Β', 'B'), ('Ε', 'E'), ('Ζ', 'Z'), ('Η', 'H'),
+        ('Ι', 'I'), ('Κ', 'K'), ('Μ', 'M'), ('Ν', 'N'), ('Ο', 'O'), ('Ρ', 'P'), ('Τ', 'T'),
+        ('Υ', 'Y'), ('Χ', 'X'),
+        ('\u{2010}', '-'), ('\u{2011}', '-'), ('\u{2012}', '-'), ('\u{2013}', '-'),
+        ('\u{2014}', '-'), ('\u{2015}', '-'), ('\u{2212}', '-'),
+    ];
 
     /// Checks validity of option set, returning details of any problems
     ///
@@ -341,6 +1110,16 @@ mod validation {
                     false => { return Err(flaws); },
                 }
             }
+            if let Some(default) = candidate.default {
+                let possible = candidate.possible_values.is_empty()
+                    || candidate.possible_values.contains(&default);
+                if !candidate.expects_data || !possible {
+                    match detail {
+                        true => { flaws.push(OptionFlaw::LongDefaultInvalid(candidate.name)); },
+                        false => { return Err(flaws); },
+                    }
+                }
+            }
         }
 
         for candidate in set.short {
@@ -362,6 +1141,31 @@ mod validation {
             return Err(flaws);
         }
 
+        let last_index = set.positionals.len().saturating_sub(1);
+        for (i, candidate) in set.positionals.iter().enumerate() {
+            if candidate.name.is_empty() {
+                match detail {
+                    true => { flaws.push(OptionFlaw::PositionalEmpty); },
+                    false => { return Err(flaws); },
+                }
+            }
+            let name = candidate.name;
+            let flaw = match candidate.max {
+                Some(max) if max < candidate.min => OptionFlaw::PositionalMaxBelowMin(name),
+                None if i != last_index => OptionFlaw::PositionalUnboundedNotLast(name),
+                _ => { continue; },
+            };
+            match detail {
+                true => { flaws.push(flaw); },
+                false => { return Err(flaws); },
+            }
+        }
+
+        find_duplicates_positional(set, &mut flaws, detail, &mut dupes);
+        if !detail && dupes {
+            return Err(flaws);
+        }
+
         match flaws.is_empty() {
             true => Ok(()),
             false => Err(flaws),
@@ -398,16 +1202,49 @@ mod validation {
         detail: bool, found: &mut bool)
     {
         let opts = set.long;
-        let mut checked: Vec<&'a str> = Vec::with_capacity(opts.len());
+        // Names are compared in normalized form, with each normalized name reported only once
+        let mut checked: Vec<Cow<'a, str>> = Vec::with_capacity(opts.len());
+        let mut reported: Vec<Cow<'a, str>> = Vec::new();
+        let normalization = set.name_normalization();
 
         let mut duplicates = Vec::new();
         for long in opts {
-            let name = long.name.clone();
-            if !duplicates.contains(&OptionFlaw::LongDup(name)) {
+            let name = long.name;
+            let normalized = normalization.apply(name);
+            if !reported.contains(&normalized) {
+                match checked.contains(&normalized) {
+                    true => {
+                        match detail {
+                            true => {
+                                duplicates.push(OptionFlaw::LongDup(name));
+                                reported.push(normalized);
+                            },
+                            false => { *found = true; return; },
+                        }
+                    },
+                    false => { checked.push(normalized); },
+                }
+            }
+        }
+        if !duplicates.is_empty() {
+            flaws.append(&mut duplicates);
+        }
+    }
+
+    fn find_duplicates_positional<'r, 'a: 'r>(set: &OptionSet<'r, 'a>,
+        flaws: &mut Vec<OptionFlaw<'a>>, detail: bool, found: &mut bool)
+    {
+        let opts = set.positionals;
+        let mut checked: Vec<&'a str> = Vec::with_capacity(opts.len());
+
+        let mut duplicates = Vec::new();
+        for positional in opts {
+            let name = positional.name;
+            if !duplicates.contains(&OptionFlaw::PositionalDup(name)) {
                 match checked.contains(&name) {
                     true => {
                         match detail {
-                            true => { duplicates.push(OptionFlaw::LongDup(name)); },
+                            true => { duplicates.push(OptionFlaw::PositionalDup(name)); },
                             false => { *found = true; return; },
                         }
                     },
@@ -419,6 +1256,311 @@ mod validation {
             flaws.append(&mut duplicates);
         }
     }
+
+    /// Compile-time counterpart of `validate_set`, giving only the first flaw found
+    ///
+    /// Checks are done in the same order. Long option names are compared for duplicates with only
+    /// ASCII case folding and separator folding applied, being all that is possible here.
+    pub const fn first_flaw<'r, 'a: 'r>(set: &OptionSet<'r, 'a>) -> Option<OptionFlaw<'a>> {
+        let mut i = 0;
+        while i < set.long.len() {
+            let candidate = &set.long[i];
+            if candidate.name.is_empty() {
+                return Some(OptionFlaw::LongEmpty);
+            }
+            else if contains_byte(candidate.name, b'=') {
+                return Some(OptionFlaw::LongIncludesEquals(candidate.name));
+            }
+            if let Some(default) = candidate.default {
+                let possible = candidate.possible_values.is_empty()
+                    || contains_name(candidate.possible_values, default);
+                if !candidate.expects_data || !possible {
+                    return Some(OptionFlaw::LongDefaultInvalid(candidate.name));
+                }
+            }
+            i += 1;
+        }
+
+        let mut i = 0;
+        while i < set.short.len() {
+            let ch = set.short[i].ch;
+            if ch == '-' {
+                return Some(OptionFlaw::ShortDash);
+            }
+            i += 1;
+        }
+
+        let mut i = 0;
+        while i < set.short.len() {
+            let mut j = 0;
+            while j < i {
+                if set.short[j].ch == set.short[i].ch {
+                    return Some(OptionFlaw::ShortDup(set.short[i].ch));
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+
+        // Case folding is skipped with a Unicode normalization form, since composition could make
+        // names differing only in ASCII case differ in other respects.
+        let unicode_none = matches!(set.normalization.unicode, UnicodeForm::None);
+        let fold_case = unicode_none && match set.normalization.case {
+            CaseFolding::None => set.mode.syntax().case_insensitive,
+            CaseFolding::Ascii | CaseFolding::Unicode => true,
+        };
+        let fold_separators = set.normalization.fold_separators;
+        let mut i = 0;
+        while i < set.long.len() {
+            let mut j = 0;
+            while j < i {
+                if names_eq(set.long[j].name, set.long[i].name, fold_case, fold_separators) {
+                    return Some(OptionFlaw::LongDup(set.long[i].name));
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+
+        let mut i = 0;
+        while i < set.positionals.len() {
+            let candidate = &set.positionals[i];
+            if candidate.name.is_empty() {
+                return Some(OptionFlaw::PositionalEmpty);
+            }
+            match candidate.max {
+                Some(max) if max < candidate.min => {
+                    return Some(OptionFlaw::PositionalMaxBelowMin(candidate.name));
+                },
+                None if i != set.positionals.len() - 1 => {
+                    return Some(OptionFlaw::PositionalUnboundedNotLast(candidate.name));
+                },
+                _ => {},
+            }
+            i += 1;
+        }
+
+        let mut i = 0;
+        while i < set.positionals.len() {
+            let mut j = 0;
+            while j < i {
+                if names_eq(set.positionals[j].name, set.positionals[i].name, false, false) {
+                    return Some(OptionFlaw::PositionalDup(set.positionals[i].name));
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+
+        None
+    }
+
+    /// Describes a flaw, for compile-time failure messages (which cannot include formatted data)
+    pub const fn describe_flaw(flaw: &OptionFlaw) -> &'static str {
+        match *flaw {
+            OptionFlaw::LongEmpty => "invalid option set: long option name is empty",
+            OptionFlaw::LongIncludesEquals(_) =>
+                "invalid option set: long option name contains `=`",
+            OptionFlaw::ShortDash => "invalid option set: short option is dash (`-`)",
+            OptionFlaw::ShortDup(_) => "invalid option set: duplicate short option",
+            OptionFlaw::LongDup(_) => "invalid option set: duplicate long option",
+            OptionFlaw::LongDefaultInvalid(_) => "invalid option set: invalid long option default",
+            OptionFlaw::PositionalEmpty => "invalid option set: positional name is empty",
+            OptionFlaw::PositionalDup(_) => "invalid option set: duplicate positional",
+            OptionFlaw::PositionalMaxBelowMin(_) =>
+                "invalid option set: positional maximum is below its minimum",
+            OptionFlaw::PositionalUnboundedNotLast(_) =>
+                "invalid option set: positional with unlimited maximum is not the last",
+        }
+    }
+
+    /// Checks if `name` contains the given byte
+    const fn contains_byte(name: &str, byte: u8) -> bool {
+        let bytes = name.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == byte {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Checks if `list` contains `name` (exactly)
+    const fn contains_name(list: &[&str], name: &str) -> bool {
+        let mut i = 0;
+        while i < list.len() {
+            if names_eq(list[i], name, false, false) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Compares names, optionally with ASCII case folding and dash/underscore folding
+    const fn names_eq(a: &str, b: &str, fold_case: bool, fold_separators: bool) -> bool {
+        let (a, b) = (a.as_bytes(), b.as_bytes());
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < a.len() {
+            let (mut x, mut y) = (a[i], b[i]);
+            if fold_case {
+                x = x.to_ascii_lowercase();
+                y = y.to_ascii_lowercase();
+            }
+            if fold_separators {
+                if x == b'_' { x = b'-'; }
+                if y == b'_' { y = b'-'; }
+            }
+            if x != y {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Builds a validation report
+    ///
+    /// Problems are found in the same order as with `validate_set`, with lints for each option
+    /// following any problems with it.
+    pub fn report<'r, 'a: 'r>(set: &OptionSet<'r, 'a>) -> Vec<ValidationFinding<'a>> {
+        let mut findings = Vec::new();
+        let mut add = |location, kind| { findings.push(ValidationFinding { location, kind }); };
+
+        for (i, candidate) in set.long.iter().enumerate() {
+            let location = FindingLocation::Long(i, candidate.name);
+            if candidate.name.is_empty() {
+                add(location, FindingKind::Flaw(OptionFlaw::LongEmpty));
+            }
+            else if candidate.name.contains('=') {
+                add(location, FindingKind::Flaw(OptionFlaw::LongIncludesEquals(candidate.name)));
+            }
+            if let Some(default) = candidate.default {
+                let possible = candidate.possible_values.is_empty()
+                    || candidate.possible_values.contains(&default);
+                if !candidate.expects_data || !possible {
+                    let flaw = OptionFlaw::LongDefaultInvalid(candidate.name);
+                    add(location, FindingKind::Flaw(flaw));
+                }
+            }
+            if candidate.name.starts_with('-') {
+                add(location, FindingKind::Lint(OptionLint::LeadingDash));
+            }
+            for lint in char_lints(candidate.name.chars()) {
+                add(location, FindingKind::Lint(lint));
+            }
+        }
+
+        let shorts_supported = set.mode.syntax().short_prefix.is_some();
+        for (i, candidate) in set.short.iter().enumerate() {
+            let location = FindingLocation::Short(i, candidate.ch);
+            if candidate.ch == '-' {
+                add(location, FindingKind::Flaw(OptionFlaw::ShortDash));
+            }
+            if set.negative_numbers && candidate.ch.is_ascii_digit() {
+                add(location, FindingKind::Lint(OptionLint::DigitWithNegativeNumbers));
+            }
+            if !shorts_supported {
+                add(location, FindingKind::Lint(OptionLint::ShortUnsupported));
+            }
+            for lint in char_lints(Some(candidate.ch).into_iter()) {
+                add(location, FindingKind::Lint(lint));
+            }
+        }
+
+        let chars: Vec<char> = set.short.iter().map(|o| o.ch).collect();
+        for i in duplicate_indices(&chars) {
+            let location = FindingLocation::Short(i, chars[i]);
+            add(location, FindingKind::Flaw(OptionFlaw::ShortDup(chars[i])));
+        }
+
+        let normalization = set.name_normalization();
+        let names: Vec<Cow<'a, str>> = set.long.iter()
+            .map(|o| normalization.apply(o.name))
+            .collect();
+        for i in duplicate_indices(&names) {
+            let name = set.long[i].name;
+            add(FindingLocation::Long(i, name), FindingKind::Flaw(OptionFlaw::LongDup(name)));
+        }
+
+        let last_index = set.positionals.len().saturating_sub(1);
+        for (i, candidate) in set.positionals.iter().enumerate() {
+            let name = candidate.name;
+            let location = FindingLocation::Positional(i, name);
+            if name.is_empty() {
+                add(location, FindingKind::Flaw(OptionFlaw::PositionalEmpty));
+            }
+            match candidate.max {
+                Some(max) if max < candidate.min => {
+                    add(location, FindingKind::Flaw(OptionFlaw::PositionalMaxBelowMin(name)));
+                },
+                None if i != last_index => {
+                    add(location, FindingKind::Flaw(OptionFlaw::PositionalUnboundedNotLast(name)));
+                },
+                _ => {},
+            }
+        }
+
+        let names: Vec<&'a str> = set.positionals.iter().map(|o| o.name).collect();
+        for i in duplicate_indices(&names) {
+            let location = FindingLocation::Positional(i, names[i]);
+            add(location, FindingKind::Flaw(OptionFlaw::PositionalDup(names[i])));
+        }
+
+        findings
+    }
+
+    /// Gives the indices of duplicates, at the first repeat of each distinct key only
+    fn duplicate_indices<T: PartialEq>(keys: &[T]) -> Vec<usize> {
+        let mut indices = Vec::new();
+        for (i, key) in keys.iter().enumerate() {
+            if keys[..i].contains(key) && !indices.iter().any(|&j| keys[j] == *key) {
+                indices.push(i);
+            }
+        }
+        indices
+    }
+
+    /// Gives lints for the characters of a name, with each type given only once
+    fn char_lints<I: Iterator<Item = char>>(chars: I) -> Vec<OptionLint> {
+        let mut lints = Vec::new();
+        for ch in chars {
+            let lint = if ch.is_control() {
+                OptionLint::ControlChar
+            }
+            else if ch.is_whitespace() {
+                OptionLint::Whitespace
+            }
+            else if ZERO_WIDTH.contains(&ch) {
+                OptionLint::ZeroWidth(ch)
+            }
+            else if let Some(ascii) = confusable(ch) {
+                OptionLint::Confusable(ch, ascii)
+            }
+            else {
+                continue;
+            };
+            let kind = std::mem::discriminant(&lint);
+            if !lints.iter().any(|l| std::mem::discriminant(l) == kind) {
+                lints.push(lint);
+            }
+        }
+        lints
+    }
+
+    /// Gives the ASCII character `ch` is easily confused with, if any
+    fn confusable(ch: char) -> Option<char> {
+        // Fullwidth forms of ASCII characters
+        if ('\u{ff01}'..='\u{ff5e}').contains(&ch) {
+            return std::char::from_u32(ch as u32 - 0xfee0);
+        }
+        CONFUSABLES.iter().find(|&&(c, _)| c == ch).map(|&(_, ascii)| ascii)
+    }
 }
 
 #[cfg(test)]
@@ -453,4 +1595,11 @@ mod tests {
     fn create_long_with_equals() {
         let _opt = LongOption::new("a=b", false); // Should panic here in debug mode!
     }
+
+    /// Check `Positional::new` rejects empty string
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn create_positional_no_name() {
+        let _opt = Positional::new("", 1, Some(1)); // Should panic here in debug mode!
+    }
 }
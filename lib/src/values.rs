@@ -0,0 +1,258 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Option value resolution
+//!
+//! An [`Analysis`] only describes what was found on the command line. This module provides a
+//! “query layer” on top of it, [`OptionValues`], which can additionally be filled in from other
+//! sources, for options not given on the command line, and which records where each value came
+//! from.
+//!
+//! Sources take precedence in the following order:
+//!
+//!  1. The command line
+//!  2. Environment variables (see [`LongOption::env`])
+//!  3. Configuration files (see the [`config`] module), with files added later taking precedence
+//!     over those added earlier (thus add system-wide files before per-user ones)
+//!  4. Declared default values (see [`LongOption::default`])
+//!
+//! This precedence applies regardless of the order in which sources are added. Only *long options*
+//! are covered.
+//!
+//! [`Analysis`]: ../analysis/struct.Analysis.html
+//! [`OptionValues`]: struct.OptionValues.html
+//! [`LongOption::env`]: ../options/struct.LongOption.html#structfield.env
+//! [`LongOption::default`]: ../options/struct.LongOption.html#structfield.default
+//! [`config`]: ../config/index.html
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::BuildHasher;
+use std::path::Path;
+use super::analysis::*;
+use super::config::ConfigFile;
+use super::engine::match_value;
+use super::options::*;
+
+/// Source of environment variable values
+///
+/// This allows substituting the real process environment, for instance with a fake one in tests.
+pub trait EnvSource {
+    /// Gets the value of the named variable, if set
+    fn var(&self, name: &str) -> Option<String>;
+}
+
+/// The real process environment
+///
+/// Note, variables with values that are not valid Unicode are treated as not set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProcessEnv;
+
+impl EnvSource for ProcessEnv {
+    fn var(&self, name: &str) -> Option<String> {
+        ::std::env::var(name).ok()
+    }
+}
+
+impl<S: BuildHasher> EnvSource for HashMap<String, String, S> {
+    fn var(&self, name: &str) -> Option<String> {
+        self.get(name).cloned()
+    }
+}
+
+impl<'s> EnvSource for [(&'s str, &'s str)] {
+    fn var(&self, name: &str) -> Option<String> {
+        self.iter().find(|&&(n, _)| n == name).map(|&(_, v)| String::from(v))
+    }
+}
+
+/// Where a value came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueSource<'a> {
+    /// Given on the command line, in the argument at the given index
+    CommandLine(usize),
+    /// Taken from the named environment variable
+    Environment(&'a str),
+    /// Taken from a configuration file, at the given (one-based) line
+    ConfigFile { path: &'a Path, line: usize },
+    /// The option’s declared default value
+    Default,
+}
+
+impl<'a> fmt::Display for ValueSource<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ValueSource::CommandLine(i) => write!(f, "command line (argument {})", i),
+            ValueSource::Environment(var) => write!(f, "environment variable `{}`", var),
+            ValueSource::ConfigFile { path, line } =>
+                write!(f, "config file `{}` (line {})", path.display(), line),
+            ValueSource::Default => write!(f, "default"),
+        }
+    }
+}
+
+/// A resolved option value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionValue<'a> {
+    /// Long option name
+    pub name: &'a str,
+    /// Data value (always `None` for flag type options)
+    pub data: Option<Cow<'a, str>>,
+    /// Whether given in negated form (e.g. `++foo` with plus prefix support, or `/foo-` in DOS
+    /// mode), explicitly turning the option off
+    pub negated: bool,
+    /// Where it came from
+    pub source: ValueSource<'a>,
+}
+
+impl<'a> ValueSource<'a> {
+    /// Precedence rank, higher taking precedence over lower
+    fn rank(&self) -> u8 {
+        match *self {
+            ValueSource::CommandLine(_) => 3,
+            ValueSource::Environment(_) => 2,
+            ValueSource::ConfigFile { .. } => 1,
+            ValueSource::Default => 0,
+        }
+    }
+}
+
+/// Option values, resolved from the command line and other sources
+///
+/// Values are held in the order added (command line values in argument order), minus any replaced
+/// by a source with higher precedence.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OptionValues<'a> {
+    /// The values
+    pub values: Vec<OptionValue<'a>>,
+    /// Values rejected for not being one of the option’s possible values (those from environment
+    /// variables; invalid values from other sources are reported in their analysis)
+    pub invalid: Vec<OptionValue<'a>>,
+}
+
+impl<'a> OptionValues<'a> {
+    /// Collects the values of the *long options* found on the command line
+    ///
+    /// Only successfully matched options are collected; problem items are ignored. Negated options
+    /// are collected as values with `negated` set, such that other sources do not override them.
+    pub fn from_analysis(analysis: &Analysis<'a>) -> Self {
+        let mut values = Vec::new();
+        for item in &analysis.items {
+            let (i, name, data, negated) = match *item {
+                ItemClass::Ok(Item::Long(i, n)) => (i, n, None, false),
+                ItemClass::Ok(Item::LongNegated(i, n)) => (i, n, None, true),
+                ItemClass::Ok(Item::LongWithData { i, n, v, .. }) =>
+                    (i, n, Some(Cow::Borrowed(v)), false),
+                _ => { continue; },
+            };
+            let source = ValueSource::CommandLine(i);
+            values.push(OptionValue { name, data, negated, source });
+        }
+        Self { values, invalid: Vec::new() }
+    }
+
+    /// Fills in values from environment variables, for options in `set` that have an environment
+    /// variable binding
+    ///
+    /// For flag type options, the variable being set to a non-empty value is taken as the option
+    /// being given. For data taking options, the variable’s value is the data value (which may be
+    /// empty). Data values must match one of any possible values of the option exactly; those that
+    /// do not are not used, being added to `invalid` instead.
+    pub fn add_env<E>(&mut self, set: &OptionSet<'_, 'a>, env: &E) -> &mut Self
+        where E: EnvSource + ?Sized
+    {
+        for option in set.long {
+            let var = match option.env {
+                Some(var) => var,
+                None => { continue; },
+            };
+            let data = match (env.var(var), option.expects_data) {
+                (Some(value), true) => Some(Cow::Owned(value)),
+                (Some(ref value), false) if !value.is_empty() => None,
+                _ => { continue; },
+            };
+            let source = ValueSource::Environment(var);
+            let value = OptionValue { name: option.name, data, negated: false, source };
+            match value.data.as_ref().map(|d| match_value(option, d, false)) {
+                Some(None) => self.invalid.push(value),
+                _ => self.fill(value),
+            }
+        }
+        self
+    }
+
+    /// Fills in values from a configuration file, given along with its analysis (see
+    /// [`ConfigFile::analyse`](../config/struct.ConfigFile.html#method.analyse))
+    ///
+    /// Only successfully matched entries are used; problem items are ignored. Flags set to `false`
+    /// are added as values with `negated` set, such that they override earlier files.
+    pub fn add_config(&mut self, config: &'a ConfigFile, analysis: &Analysis<'a>) -> &mut Self {
+        for item in &analysis.items {
+            let (line, name, data, negated) = match *item {
+                ItemClass::Ok(Item::Long(i, n)) => (i, n, None, false),
+                ItemClass::Ok(Item::LongNegated(i, n)) => (i, n, None, true),
+                ItemClass::Ok(Item::LongWithData { i, n, v, .. }) =>
+                    (i, n, Some(Cow::Borrowed(v)), false),
+                _ => { continue; },
+            };
+            let source = ValueSource::ConfigFile { path: &config.path, line };
+            self.fill(OptionValue { name, data, negated, source });
+        }
+        self
+    }
+
+    /// Fills in the default values of options in `set` that declare one
+    ///
+    /// Defaults take the lowest precedence, thus only apply to options not given by any other
+    /// source.
+    pub fn add_defaults(&mut self, set: &OptionSet<'_, 'a>) -> &mut Self {
+        for option in set.long {
+            if let Some(default) = option.default {
+                let data = Some(Cow::Borrowed(default));
+                let source = ValueSource::Default;
+                self.fill(OptionValue { name: option.name, data, negated: false, source });
+            }
+        }
+        self
+    }
+
+    /// Adds a value, unless a value from a source of higher precedence is already held, replacing
+    /// any from sources of equal or lower precedence
+    fn fill(&mut self, value: OptionValue<'a>) {
+        let rank = value.source.rank();
+        let name = value.name;
+        if self.values.iter().any(|v| v.name == name && v.source.rank() > rank) {
+            return;
+        }
+        self.values.retain(|v| v.name != name);
+        self.values.push(value);
+    }
+
+    /// Checks whether the named option is given, i.e. a value is held for it, and the last
+    /// instance is not negated
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some_and(|v| !v.negated)
+    }
+
+    /// Gets the value for the named option
+    ///
+    /// If the option was given more than once, the last instance is returned.
+    pub fn get(&self, name: &str) -> Option<&OptionValue<'a>> {
+        self.values.iter().rev().find(|v| v.name == name)
+    }
+
+    /// Gets the data value for the named option, if any
+    ///
+    /// If the option was given more than once, the last instance is used.
+    pub fn get_data(&self, name: &str) -> Option<&str> {
+        self.get(name).and_then(|v| v.data.as_ref()).map(|d| d.as_ref())
+    }
+}
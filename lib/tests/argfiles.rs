@@ -0,0 +1,153 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Testing response file (`@file`) argument expansion
+
+#[macro_use]
+extern crate gong;
+
+#[allow(unused_macros)]
+#[allow(dead_code)] //Mod shared across test crates
+#[macro_use]
+mod common;
+
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use gong::analysis::*;
+use gong::argfiles::*;
+use common::get_base;
+
+/// Scratch directory for a test, removed (along with its content) when dropped
+struct ScratchDir(PathBuf);
+
+impl Deref for ScratchDir {
+    type Target = Path;
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Creates a fresh scratch directory for a test
+fn scratch_dir(test: &str) -> ScratchDir {
+    let dir = std::env::temp_dir().join(format!("gong-argfiles-{}-{}", std::process::id(), test));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    ScratchDir(dir)
+}
+
+/// Check basic expansion, with origin tracking
+#[test]
+fn basic() {
+    let dir = scratch_dir("basic");
+    let file = dir.join("args.txt");
+    fs::write(&file, "# Comment\n--foo 'a b'\n\n-o \"x\"\n").unwrap();
+    let file_arg = format!("@{}", file.display());
+
+    let args = arg_list!("abc", &file_arg[..], "@", "--help");
+    let expansion = expand(&args).unwrap();
+    assert_eq!(expansion.args, vec!["abc", "--foo", "a b", "-o", "x", "@", "--help"]);
+    assert_eq!(expansion.origins, vec![
+        ArgOrigin::CommandLine(0),
+        ArgOrigin::File { file: 0, line: 2 },
+        ArgOrigin::File { file: 0, line: 2 },
+        ArgOrigin::File { file: 0, line: 4 },
+        ArgOrigin::File { file: 0, line: 4 },
+        ArgOrigin::CommandLine(2),
+        ArgOrigin::CommandLine(3),
+    ]);
+    assert_eq!(expansion.files, vec![file.clone()]);
+    assert_eq!(expansion.describe_origin(0), Some(String::from("argument 0")));
+    assert_eq!(expansion.describe_origin(3), Some(format!("{}:4", file.display())));
+    assert_eq!(expansion.describe_origin(7), None);
+
+    // Analysis indices can be traced back
+    let analysis = get_base().process(&expansion.args);
+    assert_eq!(analysis.items[2], ItemClass::Ok(Item::NonOption(2, "a b")));
+    assert_eq!(expansion.origins[2], ArgOrigin::File { file: 0, line: 2 });
+}
+
+/// Check nesting, and re-use of the same file
+#[test]
+fn nested() {
+    let dir = scratch_dir("nested");
+    let inner = dir.join("inner.txt");
+    let outer = dir.join("outer.txt");
+    fs::write(&inner, "-x").unwrap();
+    fs::write(&outer, format!("--foo\n@{}\n--help", inner.display())).unwrap();
+    let outer_arg = format!("@{}", outer.display());
+    let inner_arg = format!("@{}", inner.display());
+
+    let args = arg_list!(&outer_arg[..], &inner_arg[..]);
+    let expansion = expand(&args).unwrap();
+    assert_eq!(expansion.args, vec!["--foo", "-x", "--help", "-x"]);
+    assert_eq!(expansion.origins, vec![
+        ArgOrigin::File { file: 0, line: 1 },
+        ArgOrigin::File { file: 1, line: 1 },
+        ArgOrigin::File { file: 0, line: 3 },
+        ArgOrigin::File { file: 1, line: 1 },
+    ]);
+    assert_eq!(expansion.files, vec![outer, inner]);
+
+    // Depth limit
+    match expand_with_limit(&args, 1) {
+        Err(ExpandError::TooDeep { .. }) => {},
+        other => panic!("unexpected result: {:?}", other),
+    }
+    match expand_with_limit(&args, 0) {
+        Err(ExpandError::TooDeep { .. }) => {},
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+/// Check cycles are caught
+#[test]
+fn cycle() {
+    let dir = scratch_dir("cycle");
+    let a = dir.join("a.txt");
+    let b = dir.join("b.txt");
+    fs::write(&a, format!("-x @{}", b.display())).unwrap();
+    fs::write(&b, format!("-h @{}", a.display())).unwrap();
+    let a_arg = format!("@{}", a.display());
+
+    let args = arg_list!(&a_arg[..]);
+    match expand(&args) {
+        Err(ExpandError::Cycle { path }) => { assert_eq!(path, a); },
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+/// Check failures
+#[test]
+fn errors() {
+    let dir = scratch_dir("errors");
+    let missing = dir.join("missing.txt");
+    let missing_arg = format!("@{}", missing.display());
+    let args = arg_list!(&missing_arg[..]);
+    match expand(&args) {
+        Err(ExpandError::Io { path, .. }) => { assert_eq!(path, missing); },
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    let bad = dir.join("bad.txt");
+    fs::write(&bad, "-x\n'unterminated\n").unwrap();
+    let bad_arg = format!("@{}", bad.display());
+    let args = arg_list!(&bad_arg[..]);
+    match expand(&args) {
+        Err(ExpandError::UnterminatedQuote { path, line: 2 }) => { assert_eq!(path, bad); },
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Testing canonical argument list reconstruction
+
+// Construction macros cannot know when every attribute has been given
+#![allow(clippy::needless_update)]
+
+#[macro_use]
+extern crate gong;
+
+#[allow(unused_macros)]
+#[allow(dead_code)] //Mod shared across test crates
+#[macro_use]
+mod common;
+
+use gong::analysis::*;
+use gong::options::*;
+use common::get_base;
+
+/// Gives an item with argument index and data location zeroed, for equivalence comparison
+fn without_position(item: ItemClass) -> ItemClass {
+    const L: DataLocation = DataLocation::SameArg;
+    match item {
+        ItemClass::Ok(item) => ItemClass::Ok(match item {
+            Item::NonOption(_, s) => Item::NonOption(0, s),
+            Item::EarlyTerminator(_) => Item::EarlyTerminator(0),
+            Item::PassThrough(_, s) => Item::PassThrough(0, s),
+            Item::Long(_, n) => Item::Long(0, n),
+            Item::LongNegated(_, n) => Item::LongNegated(0, n),
+            Item::LongWithData { n, v, .. } => Item::LongWithData { i: 0, n, d: v, v, l: L },
+            Item::Short(_, c) => Item::Short(0, c),
+            Item::ShortNegated(_, c) => Item::ShortNegated(0, c),
+            Item::ShortWithData { c, d, .. } => Item::ShortWithData { i: 0, c, d, l: L },
+        }),
+        ItemClass::Warn(item) => ItemClass::Warn(match item {
+            ItemW::UnknownLong(_, n) => ItemW::UnknownLong(0, n),
+            ItemW::UnknownShort(_, c) => ItemW::UnknownShort(0, c),
+            ItemW::LongWithNoName(_) => ItemW::LongWithNoName(0),
+            ItemW::LongWithUnexpectedData { n, d, .. } => {
+                ItemW::LongWithUnexpectedData { i: 0, n, d }
+            },
+            ItemW::LateOption(_) => ItemW::LateOption(0),
+            ItemW::LongNotNegatable(_, n) => ItemW::LongNotNegatable(0, n),
+            ItemW::ShortNotNegatable(_, c) => ItemW::ShortNotNegatable(0, c),
+            ItemW::LongDeprecated(_, n, r) => ItemW::LongDeprecated(0, n, r),
+            ItemW::ShortDeprecated(_, c, r) => ItemW::ShortDeprecated(0, c, r),
+            ItemW::LongAbbreviated(_, n) => ItemW::LongAbbreviated(0, n),
+        }),
+        ItemClass::Err(item) => ItemClass::Err(match item {
+            ItemE::LongMissingData(_, n) => ItemE::LongMissingData(0, n),
+            ItemE::ShortMissingData(_, c) => ItemE::ShortMissingData(0, c),
+            ItemE::AmbiguousLong(_, n) => ItemE::AmbiguousLong(0, n),
+            ItemE::LongInvalidData { n, d, v, .. } => {
+                ItemE::LongInvalidData { i: 0, n, d, l: L, v }
+            },
+        }),
+    }
+}
+
+/// Checks that reprocessing the canonical form of the given arguments gives an equivalent analysis,
+/// for both data forms
+fn check_round_trip(opts: &OptionSet, args: &[&str]) {
+    let analysis = opts.process(args);
+    for &form in &[DataLocation::SameArg, DataLocation::NextArg] {
+        let canonical = analysis.canonical_args(opts.mode, form);
+        let reprocessed = opts.process(&canonical);
+        assert_eq!(analysis.error, reprocessed.error, "canonical: {:?}", canonical);
+        assert_eq!(analysis.warn, reprocessed.warn, "canonical: {:?}", canonical);
+        let expected: Vec<_> = analysis.items.iter().cloned().map(without_position).collect();
+        let actual: Vec<_> = reprocessed.items.iter().cloned().map(without_position).collect();
+        assert_eq!(expected, actual, "canonical: {:?}", canonical);
+        // And the canonical form is stable
+        assert_eq!(canonical, reprocessed.canonical_args(opts.mode, form));
+    }
+}
+
+/// Check the canonical form itself
+#[test]
+fn basic() {
+    let args = arg_list!(
+        "abc",          // Non-option
+        "--fooba",      // Abbreviated
+        "-hx",          // Short set
+        "--hah=data",   // Long with data, in same arg
+        "-o", "data",   // Short with data, in next arg
+        "-xoarg",       // Short set, with data
+        "--",           // Early terminator
+        "--help",       // Non-option
+    );
+    let analysis = get_base().process(&args);
+
+    let canonical = analysis.canonical_args(OptionsMode::Standard, DataLocation::SameArg);
+    assert_eq!(canonical, vec![
+        "abc", "--foobar", "-h", "-x", "--hah=data", "-odata", "-x", "-oarg", "--", "--help",
+    ]);
+
+    let canonical = analysis.canonical_args(OptionsMode::Standard, DataLocation::NextArg);
+    assert_eq!(canonical, vec![
+        "abc", "--foobar", "-h", "-x", "--hah", "data", "-o", "data", "-x", "-o", "arg", "--",
+        "--help",
+    ]);
+}
+
+/// Check round trip with standard mode, over a range of inputs including problem items
+#[test]
+fn round_trip() {
+    let opts = get_base().clone();
+    check_round_trip(&opts, &arg_list!(
+        "abc",          // Non-option
+        "--foo",        // Long
+        "--vers",       // Abbreviated
+        "--fo",         // Ambiguous
+        "--xyz=a",      // Unknown, with data
+        "--foo=bar",    // Unexpected data
+        "--foo=",       // Empty unexpected data, ignored
+        "--=a",         // No name
+        "-❤xz",         // Short set, with unknown
+        "-x-",          // Unknown dash in set
+        "-o",           // Short with data
+        "",             // Empty data
+        "-xo",          // Short with data, in next arg
+        "-o",           // Data
+        "--hah=",       // Empty data
+        "--hah",        // Long with data in next arg
+        "--",           // Data
+        "-",            // Non-option
+        "-ƐƐ",          // Multi-byte short with data
+        "--",           // Early terminator
+        "-x",           // Non-option
+        "--hah",        // Non-option
+    ));
+    check_round_trip(&opts, &arg_list!("-x", "--hah"));
+    check_round_trip(&opts, &arg_list!("--foo", "-o"));
+}
+
+/// Check round trip with other modes and settings
+#[test]
+fn other_modes() {
+    let mut opts = get_base().clone();
+    opts.set_mode(OptionsMode::Alternate);
+    check_round_trip(&opts, &arg_list!("abc", "-foob", "-hah=a", "-hah", "b", "-fo", "--", "-x"));
+
+    opts.set_mode(OptionsMode::Dos);
+    check_round_trip(&opts, &arg_list!("abc", "/FOOB", "/hah:a", "/hah", "b", "/foo-", "/?"));
+
+    opts.set_mode(OptionsMode::LongOnly);
+    check_round_trip(&opts, &arg_list!("-foob", "-xh", "-oarg", "-zz", "--hah=a"));
+
+    let mut opts = gong_option_set!(
+        vec![ gong_longopt!("foo", false; negatable: true), gong_longopt!("bar") ],
+        vec![ gong_shortopt!('x', false; negatable: true), gong_shortopt!('y') ];
+        plus_prefix: true,
+        ordering: OrderingPolicy::WarnLateOptions
+    );
+    check_round_trip(&opts.as_fixed(), &arg_list!("++fo", "++bar", "+xy", "abc", "-x", "--bar"));
+
+    opts.set_mode(OptionsMode::Alternate);
+    check_round_trip(&opts.as_fixed(), &arg_list!("+foo", "+bar", "-foo"));
+}
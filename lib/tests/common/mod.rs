@@ -11,6 +11,7 @@
 //! Shared stuff
 
 pub mod base;
+#[allow(unused_imports)]
 pub use self::base::get_base;
 
 use gong::analysis::Analysis;
@@ -20,6 +21,7 @@ use gong::options::*;
 // ensure they are correct however!
 pub const ABBR_SUP_DEFAULT: bool = true;
 pub const MODE_DEFAULT: OptionsMode = OptionsMode::Standard;
+pub const ORDERING_DEFAULT: OrderingPolicy = OrderingPolicy::Intermixed;
 
 /// Wrapper for actual analysis result
 #[derive(Debug)] pub struct Actual<'a>(pub Analysis<'a>);
@@ -50,23 +52,46 @@ macro_rules! expected {
 macro_rules! expected_item {
     ( $i:expr, NonOption, $s:expr ) => { ItemClass::Ok(Item::NonOption($i, $s)) };
     ( $i:expr, EarlyTerminator ) => { ItemClass::Ok(Item::EarlyTerminator($i)) };
+    ( $i:expr, PassThrough, $s:expr ) => { ItemClass::Ok(Item::PassThrough($i, $s)) };
     ( $i:expr, Long, $n:expr ) => { ItemClass::Ok(Item::Long($i, $n)) };
+    ( $i:expr, LongNegated, $n:expr ) => { ItemClass::Ok(Item::LongNegated($i, $n)) };
     ( $i:expr, Short, $c:expr ) => { ItemClass::Ok(Item::Short($i, $c)) };
+    ( $i:expr, ShortNegated, $c:expr ) => { ItemClass::Ok(Item::ShortNegated($i, $c)) };
     ( $i:expr, LongWithData, $n:expr, $d:expr, $l:expr ) => {
-        ItemClass::Ok(Item::LongWithData { i: $i, n: $n, d: $d, l: $l })
+        ItemClass::Ok(Item::LongWithData { i: $i, n: $n, d: $d, v: $d, l: $l })
+    };
+    ( $i:expr, LongWithData, $n:expr, $d:expr, $v:expr, $l:expr ) => {
+        ItemClass::Ok(Item::LongWithData { i: $i, n: $n, d: $d, v: $v, l: $l })
     };
     ( $i:expr, ShortWithData, $c:expr, $d:expr, $l:expr ) => {
         ItemClass::Ok(Item::ShortWithData { i: $i, c: $c, d: $d, l: $l })
     };
     ( $i:expr, UnknownLong, $n:expr ) => { ItemClass::Warn(ItemW::UnknownLong($i, $n)) };
     ( $i:expr, UnknownShort, $c:expr ) => { ItemClass::Warn(ItemW::UnknownShort($i, $c)) };
+    ( $i:expr, LongNotNegatable, $n:expr ) => {
+        ItemClass::Warn(ItemW::LongNotNegatable($i, $n))
+    };
+    ( $i:expr, ShortNotNegatable, $c:expr ) => {
+        ItemClass::Warn(ItemW::ShortNotNegatable($i, $c))
+    };
+    ( $i:expr, LongDeprecated, $n:expr, $r:expr ) => {
+        ItemClass::Warn(ItemW::LongDeprecated($i, $n, $r))
+    };
+    ( $i:expr, ShortDeprecated, $c:expr, $r:expr ) => {
+        ItemClass::Warn(ItemW::ShortDeprecated($i, $c, $r))
+    };
+    ( $i:expr, LongAbbreviated, $n:expr ) => { ItemClass::Warn(ItemW::LongAbbreviated($i, $n)) };
     ( $i:expr, LongWithNoName ) => { ItemClass::Warn(ItemW::LongWithNoName($i)) };
+    ( $i:expr, LateOption ) => { ItemClass::Warn(ItemW::LateOption($i)) };
     ( $i:expr, LongWithUnexpectedData, $n:expr, $d:expr ) => {
         ItemClass::Warn(ItemW::LongWithUnexpectedData { i: $i, n: $n, d: $d })
     };
     ( $i:expr, LongMissingData, $n:expr ) => { ItemClass::Err(ItemE::LongMissingData($i, $n)) };
     ( $i:expr, ShortMissingData, $c:expr ) => { ItemClass::Err(ItemE::ShortMissingData($i, $c)) };
     ( $i:expr, AmbiguousLong, $n:expr ) => { ItemClass::Err(ItemE::AmbiguousLong($i, $n)) };
+    ( $i:expr, LongInvalidData, $n:expr, $d:expr, $l:expr, $v:expr ) => {
+        ItemClass::Err(ItemE::LongInvalidData { i: $i, n: $n, d: $d, l: $l, v: $v })
+    };
 }
 
 /// Common central function for comparing actual analysis result with expected.
@@ -76,6 +101,7 @@ macro_rules! expected_item {
 /// - Fewer uses of `assert_eq`, less likely to make a typo, putting `assert_ne` by mistake
 /// - `Actual` and `Expected` wrappers help ensure correct comparison
 /// - Central place where `pretty_print_results` can be enabled and called when desired in debugging
+#[allow(clippy::assertions_on_constants)]
 pub fn check_result(actual: &Actual, expected: &Expected) {
     if actual.0 != expected.0 {
         eprintln!("Actual:");
@@ -0,0 +1,157 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Testing compiled (indexed) option sets
+//!
+//! A compiled set must give exactly the same results as the plain set it was built from, so these
+//! tests simply compare the two.
+
+// Construction macros cannot know when every attribute has been given
+#![allow(clippy::needless_update)]
+
+#[macro_use]
+extern crate gong;
+
+#[allow(unused_macros)]
+#[allow(dead_code)] //Mod shared across test crates
+#[macro_use]
+mod common;
+
+use gong::compiled::CompiledOptionSet;
+use gong::options::*;
+use common::get_base;
+
+/// A varied selection of arguments, covering exact, abbreviated, ambiguous and unknown names,
+/// with and without data, along with short option sets
+const ARGS: &[&[&str]] = &[
+    &["--help", "--foo", "--version", "--foobar", "--hah", "data", "--ábc", "--ƒƒ=x"],
+    &["--f", "--fo", "--foo", "--foob", "--fooba", "--foobarr", "--h", "--ha", "--v", "--"],
+    &["--hah=", "--ha=abc", "--ƒ=x", "--á", "--a", "--xyz", "--=", "--", "--help"],
+    &["--no-foo", "--no-fo", "--no-x", "+foo", "--help=x", "--HELP", "--Foo-Bar"],
+    &["-h", "-hx", "-xho", "data", "-oxh", "-❤", "-❤x❤", "-\u{030a}", "-Ɛ", "-Ɛdata", "-z"],
+    &["-", "-hzx", "-x-", "-❤Ɛ❤", "-q❤", "abc", "--", "-h", "--foo"],
+    &["/help", "/h", "/foo:x", "-help", "-fo", "-h", "--h"],
+];
+
+/// Checks that the compiled form of `opts` gives identical results for all of `ARGS`
+fn check(opts: &OptionSet) {
+    let compiled = CompiledOptionSet::new(opts);
+    for args in ARGS {
+        assert_eq!(compiled.process(args), opts.process(args), "args: {:?}", args);
+    }
+}
+
+/// Check with the base set
+#[test]
+fn base() {
+    check(get_base());
+}
+
+/// Check with each mode and with non-default settings
+#[test]
+fn settings() {
+    for mode in &[OptionsMode::Standard, OptionsMode::Alternate, OptionsMode::Dos] {
+        let mut opts = get_base().to_extendible();
+        opts.set_mode(*mode);
+        check(&opts.as_fixed());
+        opts.set_allow_abbreviations(false);
+        check(&opts.as_fixed());
+        opts.set_allow_abbreviations(true)
+            .set_plus_prefix(true)
+            .set_pass_through(true)
+            .set_warn_abbreviations(true);
+        check(&opts.as_fixed());
+    }
+}
+
+/// Check per-option abbreviation control, negation and retired options
+#[test]
+fn option_attributes() {
+    let mut opts = get_base().to_extendible();
+    opts.long.push(gong_longopt!("fooxyz", false; allow_abbreviation: false));
+    opts.long.push(gong_longopt!("hahaha", false; min_abbreviation: 4));
+    opts.long.push(gong_longopt!("xyz", false; negatable: true));
+    opts.long.push(gong_longopt!("foobaz", false; status: OptionStatus::Legacy));
+    opts.short.push(gong_shortopt!('z', false; status: OptionStatus::Deprecated(None)));
+    check(&opts.as_fixed());
+}
+
+/// Check that the first declared of duplicate options is used, as with the plain set
+#[test]
+fn duplicates() {
+    let mut opts = get_base().to_extendible();
+    opts.long.push(gong_longopt!("foo", true));
+    opts.long.push(gong_longopt!("ábc", true));
+    opts.short.push(gong_shortopt!('h', true));
+    opts.short.push(gong_shortopt!('❤', true));
+    opts.long.insert(0, gong_longopt!("version", true));
+    opts.short.insert(0, gong_shortopt!('Ɛ'));
+    check(&opts.as_fixed());
+}
+
+/// Check with name normalization, where distinct names may normalize to the same form
+#[test]
+fn normalization() {
+    let mut opts = get_base().to_extendible();
+    opts.long.push(gong_longopt!("Foo-Bar", true));
+    opts.long.push(gong_longopt!("foo_bar"));
+    opts.set_normalization(Normalization {
+        case: CaseFolding::Ascii,
+        fold_separators: true,
+        ..Normalization::NONE
+    });
+    check(&opts.as_fixed());
+}
+
+/// Check with a mode that adds case folding, with mixed-case declared names
+#[test]
+fn dos_case_folding() {
+    let mut opts = get_base().to_extendible();
+    opts.long.push(gong_longopt!("Verbose"));
+    opts.long.push(gong_longopt!("OUTPUT", true));
+    opts.set_mode(OptionsMode::Dos);
+    let opts = opts.as_fixed();
+    check(&opts);
+
+    let args = arg_list!("/verbose", "/VERBOSE", "/Verb", "/output:x", "/Out", "y", "/HELP");
+    let compiled = CompiledOptionSet::new(&opts);
+    let analysis = compiled.process(&args);
+    assert_eq!(analysis, opts.process(&args));
+    assert!(!analysis.warn && !analysis.error);
+}
+
+/// Check with a large generated set
+#[test]
+fn large() {
+    let names: Vec<String> = (0..2000).map(|i| format!("opt-{}-{}", i % 37, i)).collect();
+    let mut opts = get_base().to_extendible();
+    for (i, name) in names.iter().enumerate() {
+        opts.long.push(gong_longopt!(name, i % 3 == 0));
+    }
+    for ch in (0x100..0x400u32).filter_map(std::char::from_u32) {
+        opts.short.push(gong_shortopt!(ch));
+    }
+    let opts = opts.as_fixed();
+    check(&opts);
+
+    let args = arg_list!("--opt-5-5", "--opt-5-1", "--opt-12-12=x", "--opt-36-1998", "--opt-7",
+        "--opt-0-0", "data", "--opt-9-99", "-ĀāĂ", "-Ͽ", "-hЀ");
+    let compiled = CompiledOptionSet::new(&opts);
+    assert_eq!(compiled.process(&args), opts.process(&args));
+}
+
+/// Check that a compiled set can be shared between threads
+#[test]
+fn send_sync() {
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+    let compiled = CompiledOptionSet::new(get_base());
+    assert_send_sync(&compiled);
+    assert!(std::ptr::eq(compiled.options().long, get_base().long));
+}
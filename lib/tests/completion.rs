@@ -0,0 +1,206 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Testing runtime completion
+
+// Construction macros cannot know when every attribute has been given
+#![allow(clippy::needless_update)]
+
+#[macro_use]
+extern crate gong;
+
+#[allow(unused_macros)]
+#[allow(dead_code)] //Mod shared across test crates
+#[macro_use]
+mod common;
+
+use gong::completion::*;
+use gong::options::*;
+use common::get_base;
+
+fn options(list: &[&str]) -> Completion {
+    Completion::Options(list.iter().map(|s| s.to_string()).collect())
+}
+
+/// Check long option name completion
+#[test]
+fn long() {
+    let opts = get_base();
+    assert_eq!(complete(opts, &arg_list!("--fo"), 0), options(&["--foo", "--foobar"]));
+    assert_eq!(complete(opts, &arg_list!("--foo"), 0), options(&["--foo", "--foobar"]));
+    assert_eq!(complete(opts, &arg_list!("--"), 0), options(&[
+        "--help", "--foo", "--version", "--foobar", "--hah", "--a\u{301}bc", "--ƒƒ",
+    ]));
+    assert_eq!(complete(opts, &arg_list!("--xyz"), 0), options(&[]));
+    assert_eq!(complete(opts, &arg_list!("abc", "--v", "x"), 1), options(&["--version"]));
+}
+
+/// Check short option completion
+#[test]
+fn short() {
+    let opts = get_base();
+    assert_eq!(complete(opts, &arg_list!("-"), 0), options(&[
+        "--help", "--foo", "--version", "--foobar", "--hah", "--a\u{301}bc", "--ƒƒ",
+        "-h", "-❤", "-x", "-o", "-\u{030a}", "-Ɛ",
+    ]));
+    assert_eq!(complete(opts, &arg_list!("-hx"), 0), options(&["-hx"]));
+    assert_eq!(complete(opts, &arg_list!("-hz"), 0), options(&[]));
+}
+
+/// Check recognition of data positions
+#[test]
+fn data() {
+    let mut opts = get_base().clone();
+    let long_data = |name: &str, prefix: &str| {
+        let values = Vec::new();
+        Completion::LongData { name: name.to_string(), prefix: prefix.to_string(), values }
+    };
+    let short_data = |ch: char, prefix: &str| {
+        Completion::ShortData { ch, prefix: prefix.to_string() }
+    };
+    assert_eq!(complete(&opts, &arg_list!("--hah"), 1), long_data("hah", ""));
+    assert_eq!(complete(&opts, &arg_list!("--ha", "x"), 1), long_data("hah", ""));
+    assert_eq!(complete(&opts, &arg_list!("--hah=va"), 0), long_data("hah", "--hah="));
+    assert_eq!(complete(&opts, &arg_list!("-xo"), 1), short_data('o', ""));
+    assert_eq!(complete(&opts, &arg_list!("-xoval"), 0), short_data('o', "-xo"));
+    assert_eq!(complete(&opts, &arg_list!("--hah", "--f"), 1), long_data("hah", ""));
+
+    // Cursor beyond the end, taken as a new argument
+    assert_eq!(complete(&opts, &arg_list!("--hah"), 3), long_data("hah", ""));
+    assert_eq!(complete(&opts, &arg_list!("abc"), 2), complete(&opts, &arg_list!("abc"), 1));
+
+    // Unknown without abbreviations
+    opts.set_allow_abbreviations(false);
+    assert_eq!(complete(&opts, &arg_list!("--ha", "x"), 1), Completion::NonOption);
+}
+
+/// Check possible values are offered for data
+#[test]
+fn possible_values() {
+    let mut opts = get_base().to_extendible();
+    opts.long.push(gong_longopt!("color", true; possible_values: &["always", "never", "auto"]));
+    let opts = opts.as_fixed();
+    let long_data = |prefix: &str, values: &[&str]| Completion::LongData {
+        name: String::from("color"),
+        prefix: prefix.to_string(),
+        values: values.iter().map(|s| s.to_string()).collect(),
+    };
+    let all = ["always", "never", "auto"];
+    assert_eq!(complete(&opts, &arg_list!("--color"), 1), long_data("", &all));
+    assert_eq!(complete(&opts, &arg_list!("--color", "a"), 1), long_data("", &["always", "auto"]));
+    assert_eq!(complete(&opts, &arg_list!("--color=al"), 0), long_data("--color=", &["always"]));
+    assert_eq!(complete(&opts, &arg_list!("--col=x"), 0), long_data("--col=", &[]));
+}
+
+/// Check retired options are not offered
+#[test]
+fn retired() {
+    let opts = gong_option_set_fixed!(
+        [
+            gong_longopt!("foo"),
+            gong_longopt!("foo-hidden", false; status: OptionStatus::Hidden),
+            gong_longopt!("foo-old", false; status: OptionStatus::Deprecated(Some("--foo"))),
+            gong_longopt!("foo-legacy", false; status: OptionStatus::Legacy),
+        ],
+        [ gong_shortopt!('x'), gong_shortopt!('y', false; status: OptionStatus::Hidden) ]
+    );
+    // Deprecated options remain visible
+    assert_eq!(complete(&opts, &arg_list!("--fo"), 0), options(&["--foo", "--foo-old"]));
+    assert_eq!(complete(&opts, &arg_list!("-"), 0), options(&["--foo", "--foo-old", "-x"]));
+    // Though still matched
+    assert_eq!(complete(&opts, &arg_list!("-y"), 0), options(&["-y"]));
+}
+
+/// Check recognition of data positions of retired options, and with warnings following items
+#[test]
+fn retired_data() {
+    let mut opts = gong_option_set_fixed!(
+        [
+            gong_longopt!("output", true; status: OptionStatus::Deprecated(Some("--out"))),
+            gong_longopt!("legacy", true; status: OptionStatus::Legacy),
+            gong_longopt!("level", true),
+        ],
+        [
+            gong_shortopt!('o', true; status: OptionStatus::Deprecated(None)),
+            gong_shortopt!('d', false; status: OptionStatus::Deprecated(None)),
+            gong_shortopt!('l', true; status: OptionStatus::Legacy),
+        ]
+    );
+    let long_data = |name: &str, prefix: &str| {
+        let values = Vec::new();
+        Completion::LongData { name: name.to_string(), prefix: prefix.to_string(), values }
+    };
+    let short_data = |ch: char, prefix: &str| {
+        Completion::ShortData { ch, prefix: prefix.to_string() }
+    };
+
+    // Deprecated
+    assert_eq!(complete(&opts, &arg_list!("--output", ""), 1), long_data("output", ""));
+    assert_eq!(complete(&opts, &arg_list!("--output=x"), 0), long_data("output", "--output="));
+    assert_eq!(complete(&opts, &arg_list!("-o", ""), 1), short_data('o', ""));
+    assert_eq!(complete(&opts, &arg_list!("-doval"), 0), short_data('o', "-do"));
+    assert_eq!(complete(&opts, &arg_list!("-d"), 0), options(&["-d"]));
+
+    // Legacy
+    assert_eq!(complete(&opts, &arg_list!("--legacy", ""), 1), long_data("legacy", ""));
+    assert_eq!(complete(&opts, &arg_list!("--legacy=x"), 0), long_data("legacy", "--legacy="));
+    assert_eq!(complete(&opts, &arg_list!("-l", ""), 1), short_data('l', ""));
+    assert_eq!(complete(&opts, &arg_list!("--legacy", "x", ""), 2), Completion::NonOption);
+
+    // Abbreviated, with warning
+    opts.set_warn_abbreviations(true);
+    assert_eq!(complete(&opts, &arg_list!("--outp", ""), 1), long_data("output", ""));
+    assert_eq!(complete(&opts, &arg_list!("--outp=x"), 0), long_data("output", "--outp="));
+    assert_eq!(complete(&opts, &arg_list!("--lev=x"), 0), long_data("level", "--lev="));
+}
+
+/// Check recognition of non-option positions
+#[test]
+fn non_options() {
+    let mut opts = get_base().clone();
+    let none: [&str; 0] = [];
+    assert_eq!(complete(&opts, &none, 0), Completion::NonOption);
+    assert_eq!(complete(&opts, &arg_list!("ab"), 0), Completion::NonOption);
+    assert_eq!(complete(&opts, &arg_list!("--foo"), 1), Completion::NonOption);
+    assert_eq!(complete(&opts, &arg_list!("--", "--f"), 1), Completion::NonOption);
+
+    opts.set_ordering(OrderingPolicy::StopAtNonOption(1));
+    assert_eq!(complete(&opts, &arg_list!("abc", "--f"), 1), Completion::NonOption);
+    assert_eq!(complete(&opts, &arg_list!("--fooba", "--f"), 1), options(&["--foo", "--foobar"]));
+
+    opts.set_negative_numbers(true);
+    assert_eq!(complete(&opts, &arg_list!("-5"), 0), Completion::NonOption);
+}
+
+/// Check other modes and settings
+#[test]
+fn other_modes() {
+    let mut opts = get_base().clone();
+
+    opts.set_mode(OptionsMode::Alternate);
+    assert_eq!(complete(&opts, &arg_list!("-fo"), 0), options(&["-foo", "-foobar"]));
+
+    opts.set_mode(OptionsMode::Dos);
+    assert_eq!(complete(&opts, &arg_list!("/FO"), 0), options(&["/foo", "/foobar"]));
+    assert_eq!(complete(&opts, &arg_list!("/hah:x"), 0), Completion::LongData {
+        name: String::from("hah"), prefix: String::from("/hah:"), values: Vec::new() });
+
+    opts.set_mode(OptionsMode::LongOnly);
+    assert_eq!(complete(&opts, &arg_list!("-fo"), 0), options(&["-foo", "-foobar"]));
+
+    let opts = gong_option_set!(
+        vec![ gong_longopt!("foo", false; negatable: true), gong_longopt!("bar") ],
+        vec![ gong_shortopt!('x', false; negatable: true), gong_shortopt!('y') ];
+        plus_prefix: true
+    );
+    let opts = opts.as_fixed();
+    assert_eq!(complete(&opts, &arg_list!("++"), 0), options(&["++foo"]));
+    assert_eq!(complete(&opts, &arg_list!("+"), 0), options(&["++foo", "+x"]));
+}
@@ -0,0 +1,164 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Testing configuration files, and merging of their values with other sources
+
+// Construction macros cannot know when every attribute has been given
+#![allow(clippy::needless_update)]
+
+#[macro_use]
+extern crate gong;
+
+#[allow(unused_macros)]
+#[allow(dead_code)] //Mod shared across test crates
+#[macro_use]
+mod common;
+
+use std::borrow::Cow;
+use std::path::Path;
+use gong::analysis::*;
+use gong::config::*;
+use gong::values::*;
+use gong::options::*;
+use common::{Actual, Expected, check_result};
+
+static CONF_OPTS: OptionSet = gong_option_set_fixed!(
+    [
+        gong_longopt!("token", true; env: Some("MYTOOL_TOKEN")),
+        gong_longopt!("verbose"),
+        gong_longopt!("colour"),
+        gong_longopt!("user", true),
+        gong_longopt!("level", true),
+        gong_longopt!("mode", true; possible_values: &["fast", "safe"]),
+    ],
+    []
+);
+
+fn config(text: &str) -> ConfigFile {
+    ConfigFile::new("tool.conf", String::from(text))
+}
+
+/// Check entry parsing and analysis
+#[test]
+fn parsing() {
+    let file = config("\
+        # comment\n\
+        ; also a comment\n\
+        \n\
+        verbose\n\
+        colour = false\n\
+        user =  Joe Bloggs  \n\
+        token = \"a # b\" # comment\n\
+        level=''\n");
+    let expected = expected!(
+        error: false,
+        warn: false,
+        [
+            expected_item!(4, Long, "verbose"),
+            expected_item!(5, LongNegated, "colour"),
+            expected_item!(6, LongWithData, "user", "Joe Bloggs", DataLocation::SameArg),
+            expected_item!(7, LongWithData, "token", "a # b", DataLocation::SameArg),
+            expected_item!(8, LongWithData, "level", "", DataLocation::SameArg),
+        ]
+    );
+    check_result(&Actual(file.analyse(&CONF_OPTS).unwrap()), &expected);
+}
+
+/// Check problem entries are reported as with arguments
+#[test]
+fn problems() {
+    let file = config("bogus = 1\nverbose = yes\nverbose = true\nuser\n= x\nmode = fa\n\
+        mode = safe\n");
+    let expected = expected!(
+        error: true,
+        warn: true,
+        [
+            expected_item!(1, UnknownLong, "bogus"),
+            expected_item!(2, LongWithUnexpectedData, "verbose", "yes"),
+            expected_item!(3, Long, "verbose"),
+            expected_item!(4, LongMissingData, "user"),
+            expected_item!(5, LongWithNoName),
+            expected_item!(6, LongInvalidData, "mode", "fa", DataLocation::SameArg,
+                &["fast", "safe"]),
+            expected_item!(7, LongWithData, "mode", "safe", DataLocation::SameArg),
+        ]
+    );
+    check_result(&Actual(file.analyse(&CONF_OPTS).unwrap()), &expected);
+}
+
+/// Check syntax errors
+#[test]
+fn syntax_errors() {
+    let err = config("verbose\n[section]\n").analyse(&CONF_OPTS).unwrap_err();
+    assert_eq!(err, SyntaxError { line: 2, kind: SyntaxErrorKind::Section });
+    assert_eq!(err.to_string(), "line 2: sections are not supported");
+
+    let err = config("user = 'abc\n").analyse(&CONF_OPTS).unwrap_err();
+    assert_eq!(err, SyntaxError { line: 1, kind: SyntaxErrorKind::UnterminatedQuote });
+
+    let err = config("user = \"abc\" def\n").analyse(&CONF_OPTS).unwrap_err();
+    assert_eq!(err, SyntaxError { line: 1, kind: SyntaxErrorKind::TrailingCharacters });
+}
+
+/// Check precedence when merging with the command line, environment and other files
+#[test]
+fn merging() {
+    let system = config("user = root\nlevel = 1\ntoken = sys\n");
+    let user = ConfigFile::new("user.conf", String::from("level = 2\nverbose\n"));
+    let system_analysis = system.analyse(&CONF_OPTS).unwrap();
+    let user_analysis = user.analyse(&CONF_OPTS).unwrap();
+    let env = [("MYTOOL_TOKEN", "env")];
+
+    let args = arg_list!("--user", "me");
+    let analysis = CONF_OPTS.process(&args);
+
+    // Add in an order contrary to precedence, to check that does not matter
+    let mut values = OptionValues::from_analysis(&analysis);
+    values.add_config(&system, &system_analysis)
+        .add_config(&user, &user_analysis)
+        .add_env(&CONF_OPTS, &env[..]);
+    assert_eq!(values.values, vec![
+        OptionValue { name: "user", data: Some(Cow::Borrowed("me")),
+            negated: false, source: ValueSource::CommandLine(0) },
+        OptionValue { name: "level", data: Some(Cow::Borrowed("2")), negated: false,
+            source: ValueSource::ConfigFile { path: Path::new("user.conf"), line: 1 } },
+        OptionValue { name: "verbose", data: None, negated: false,
+            source: ValueSource::ConfigFile { path: Path::new("user.conf"), line: 2 } },
+        OptionValue { name: "token", data: Some(Cow::Owned(String::from("env"))),
+            negated: false, source: ValueSource::Environment("MYTOOL_TOKEN") },
+    ]);
+
+    // A later file does not override the command line
+    let mut values = OptionValues::from_analysis(&analysis);
+    values.add_env(&CONF_OPTS, &env[..]).add_config(&system, &system_analysis);
+    assert_eq!(values.get_data("user"), Some("me"));
+    assert_eq!(values.get_data("token"), Some("env"));
+    assert_eq!(values.get_data("level"), Some("1"));
+
+    // A flag turned off in a later file overrides an earlier one
+    let system = config("verbose = true\ncolour\n");
+    let user = ConfigFile::new("user.conf", String::from("verbose = false\n"));
+    let system_analysis = system.analyse(&CONF_OPTS).unwrap();
+    let user_analysis = user.analyse(&CONF_OPTS).unwrap();
+    let mut values = OptionValues::from_analysis(&analysis);
+    values.add_config(&system, &system_analysis).add_config(&user, &user_analysis);
+    let source = ValueSource::ConfigFile { path: Path::new("user.conf"), line: 1 };
+    assert_eq!(values.get("verbose"),
+        Some(&OptionValue { name: "verbose", data: None, negated: true, source }));
+    assert!(!values.contains("verbose"));
+    assert!(values.contains("colour"));
+}
+
+/// Check source description
+#[test]
+fn source_display() {
+    let source = ValueSource::ConfigFile { path: Path::new("tool.conf"), line: 3 };
+    assert_eq!(source.to_string(), "config file `tool.conf` (line 3)");
+}
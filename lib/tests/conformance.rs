@@ -0,0 +1,349 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Conformance testing against the system C library’s `getopt_long`
+//!
+//! Generated argument vectors are processed both by gong and by glibc’s `getopt_long` (through
+//! FFI), and the results compared, after conversion to a common simplified form. Differences that
+//! are by design are recorded in the [`KNOWN_DIFFERENCES`] allowlist below.
+//!
+//! Note, glibc is only available on GNU/Linux systems, thus this is restricted to them.
+
+#![cfg(all(target_os = "linux", target_env = "gnu"))]
+
+#[macro_use]
+extern crate gong;
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::sync::Mutex;
+use gong::analysis::*;
+use gong::options::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// FFI
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// glibc’s `struct option`
+#[repr(C)]
+struct CLongOption {
+    name: *const c_char,
+    has_arg: c_int,
+    flag: *mut c_int,
+    val: c_int,
+}
+
+extern "C" {
+    static mut optind: c_int;
+    static mut opterr: c_int;
+    static mut optopt: c_int;
+    static mut optarg: *mut c_char;
+
+    fn getopt_long(argc: c_int, argv: *const *mut c_char, optstring: *const c_char,
+        longopts: *const CLongOption, longindex: *mut c_int) -> c_int;
+}
+
+/// Value returned by `getopt_long` for a *non-option* (in in-order mode)
+const RET_NON_OPTION: c_int = 1;
+/// Value returned by `getopt_long` for unknown options, and unexpected data
+const RET_ERROR: c_int = b'?' as c_int;
+/// Value returned by `getopt_long` for missing data (with `:` leading the optstring)
+const RET_MISSING_DATA: c_int = b':' as c_int;
+
+/// `getopt_long` uses global state, so calls must not happen concurrently
+static GETOPT_LOCK: Mutex<()> = Mutex::new(());
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Option set
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Long options used, as name and whether data taking
+///
+/// There are deliberately options sharing prefixes, for abbreviation and ambiguity checks.
+const LONG: &[(&str, bool)] = &[
+    ("help", false),
+    ("hah", true),
+    ("foo", false),
+    ("foobar", false),
+    ("version", false),
+];
+
+/// Short options used, as char and whether data taking
+const SHORT: &[(char, bool)] = &[
+    ('h', false),
+    ('x', false),
+    ('v', false),
+    ('o', true),
+];
+
+/// Value returned by `getopt_long` for the long option at the given index
+///
+/// This lies outside of the `char` range used for short options, and is unique per option, which
+/// also avoids glibc treating abbreviations matching more than one as unambiguous.
+fn long_val(index: usize) -> c_int {
+    0x1000 + index as c_int
+}
+
+fn get_set() -> OptionSetEx<'static> {
+    let mut opts = gong_option_set!();
+    for &(name, data) in LONG {
+        match data {
+            false => opts.add_long(name),
+            true => opts.add_long_data(name),
+        };
+    }
+    for &(ch, data) in SHORT {
+        match data {
+            false => opts.add_short(ch),
+            true => opts.add_short_data(ch),
+        };
+    }
+    assert!(opts.is_valid());
+    opts
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Common result form
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Simplified result event, common to both implementations
+///
+/// Indexes and *data value* locations are not compared, since `getopt_long` does not directly
+/// provide them. glibc also does not distinguish unknown from ambiguous *long options*.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Event {
+    NonOption(String),
+    Long(String),
+    LongWithData(String, String),
+    Short(char),
+    ShortWithData(char, String),
+    /// Unknown or ambiguous long option
+    LongUnmatched,
+    LongUnexpectedData(String),
+    LongMissingData(String),
+    UnknownShort(char),
+    ShortMissingData(char),
+    /// Anything else from gong, which has no `getopt_long` equivalent
+    Other(String),
+}
+
+/// Process with gong
+fn run_gong(args: &[&str]) -> Vec<Event> {
+    let opts = get_set();
+    let analysis = opts.process(args);
+    analysis.items.iter().filter_map(|item| Some(match *item {
+        ItemClass::Ok(Item::EarlyTerminator(_)) => { return None; },
+        ItemClass::Ok(Item::NonOption(_, s)) => Event::NonOption(s.to_string()),
+        ItemClass::Ok(Item::Long(_, n)) => Event::Long(n.to_string()),
+        ItemClass::Ok(Item::LongWithData { n, v, .. }) => {
+            Event::LongWithData(n.to_string(), v.to_string())
+        },
+        ItemClass::Ok(Item::Short(_, c)) => Event::Short(c),
+        ItemClass::Ok(Item::ShortWithData { c, d, .. }) => Event::ShortWithData(c, d.to_string()),
+        ItemClass::Warn(ItemW::UnknownLong(..)) |
+        ItemClass::Err(ItemE::AmbiguousLong(..)) => Event::LongUnmatched,
+        ItemClass::Warn(ItemW::LongWithUnexpectedData { n, .. }) => {
+            Event::LongUnexpectedData(n.to_string())
+        },
+        ItemClass::Err(ItemE::LongMissingData(_, n)) => Event::LongMissingData(n.to_string()),
+        ItemClass::Warn(ItemW::UnknownShort(_, c)) => Event::UnknownShort(c),
+        ItemClass::Err(ItemE::ShortMissingData(_, c)) => Event::ShortMissingData(c),
+        ref other => Event::Other(format!("{:?}", other)),
+    })).collect()
+}
+
+/// Process with glibc `getopt_long`
+///
+/// The optstring starts with `-`, requesting that *non-options* be returned in order (as the
+/// special value `1`), rather than permuted to the end, and then `:`, requesting that missing data
+/// be distinguished from other errors (with return of `:`), and that errors not be printed.
+fn run_glibc(args: &[&str]) -> Vec<Event> {
+    let mut optstring = String::from("-:");
+    for &(ch, data) in SHORT {
+        optstring.push(ch);
+        if data {
+            optstring.push(':');
+        }
+    }
+    let optstring = CString::new(optstring).unwrap();
+
+    let long_names: Vec<CString> = LONG.iter().map(|&(n, _)| CString::new(n).unwrap()).collect();
+    let mut longopts: Vec<CLongOption> = LONG.iter().enumerate().map(|(i, &(_, data))| {
+        CLongOption {
+            name: long_names[i].as_ptr(),
+            has_arg: data as c_int,
+            flag: ptr::null_mut(),
+            val: long_val(i),
+        }
+    }).collect();
+    longopts.push(CLongOption { name: ptr::null(), has_arg: 0, flag: ptr::null_mut(), val: 0 });
+
+    let c_args: Vec<CString> = Some("prog").iter().chain(args.iter())
+        .map(|&a| CString::new(a).unwrap())
+        .collect();
+    let mut argv: Vec<*mut c_char> = c_args.iter().map(|a| a.as_ptr() as *mut c_char).collect();
+    let argc = argv.len() as c_int;
+    argv.push(ptr::null_mut());
+
+    let take_optarg = || unsafe { CStr::from_ptr(optarg).to_string_lossy().into_owned() };
+    let long_name = |val: c_int| LONG[(val - long_val(0)) as usize].0.to_string();
+    let is_long = |val: c_int| val >= long_val(0);
+
+    let _guard = GETOPT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut events = Vec::new();
+    unsafe {
+        // Zero requests full reinitialisation of glibc’s internal state
+        optind = 0;
+        opterr = 0;
+        loop {
+            let ret = getopt_long(argc, argv.as_ptr(), optstring.as_ptr(), longopts.as_ptr(),
+                ptr::null_mut());
+            let event = match ret {
+                -1 => { break; },
+                RET_NON_OPTION => Event::NonOption(take_optarg()),
+                RET_ERROR if optopt == 0 => Event::LongUnmatched,
+                RET_ERROR if is_long(optopt) => Event::LongUnexpectedData(long_name(optopt)),
+                RET_ERROR => Event::UnknownShort(optopt as u8 as char),
+                RET_MISSING_DATA if is_long(optopt) => Event::LongMissingData(long_name(optopt)),
+                RET_MISSING_DATA => Event::ShortMissingData(optopt as u8 as char),
+                val if is_long(val) => match optarg.is_null() {
+                    true => Event::Long(long_name(val)),
+                    false => Event::LongWithData(long_name(val), take_optarg()),
+                },
+                val => match optarg.is_null() {
+                    true => Event::Short(val as u8 as char),
+                    false => Event::ShortWithData(val as u8 as char, take_optarg()),
+                },
+            };
+            events.push(event);
+        }
+        // Any remaining arguments (following an early terminator) are non-options
+        for arg in &args[(optind as usize - 1)..] {
+            events.push(Event::NonOption(arg.to_string()));
+        }
+    }
+    events
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Known differences
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// A known, by design, difference in behaviour
+struct KnownDifference {
+    /// Description of the difference
+    description: &'static str,
+    /// Whether an argument triggers the difference
+    matches: fn(&str) -> bool,
+    /// Example argument triggering the difference
+    example: &'static str,
+}
+
+/// Allowlist of known differences
+///
+/// Generated argument vectors containing an argument that triggers one of these are not compared.
+///
+/// The following differences are not listed since the comparison is made such that they do not
+/// arise:
+///
+///  - `getopt_long` by default permutes the argument list, moving *non-options* to the end. We use
+///    its in-order mode, which is equivalent to gong’s default “intermixed” ordering policy.
+///  - `getopt_long` optionally treats `-W foo` as `--foo` (with `W;` in the optstring). This is not
+///    supported by gong, and not enabled.
+///  - `getopt_long` works on bytes, and so does not correctly handle multi-byte *short options*;
+///    only ASCII arguments are generated.
+const KNOWN_DIFFERENCES: &[KnownDifference] = &[
+    KnownDifference {
+        description: "An empty in-argument data value given to a flag type long option (e.g. \
+                      `--foo=`) is ignored by gong, but reported as unexpected data by glibc",
+        matches: is_flag_with_empty_data,
+        example: "--foo=",
+    },
+    KnownDifference {
+        description: "A long option argument without a name (e.g. `--=x`) is reported as such by \
+                      gong, while glibc takes the empty name as an abbreviation of every option",
+        matches: is_long_without_name,
+        example: "--=x",
+    },
+];
+
+fn is_flag_with_empty_data(arg: &str) -> bool {
+    arg.starts_with("--") && arg.ends_with('=')
+        && LONG.iter().any(|&(name, data)| !data && name.starts_with(&arg[2..(arg.len() - 1)]))
+}
+
+fn is_long_without_name(arg: &str) -> bool {
+    arg.starts_with("--=")
+}
+
+fn is_known_difference(args: &[&str]) -> bool {
+    args.iter().any(|arg| KNOWN_DIFFERENCES.iter().any(|d| (d.matches)(arg)))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Generation
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Pool of arguments from which argument vectors are generated
+const ARG_POOL: &[&str] = &[
+    "abc", "val", "-", "--",
+    "--help", "--hah", "--hah=x", "--hah=", "--foo", "--foo=x", "--foo=", "--foobar", "--foob",
+    "--fo", "--h", "--he", "--ver", "--zzz", "--zzz=x", "--=x",
+    "-h", "-x", "-v", "-o", "-hx", "-xvh", "-oval", "-xo", "-xoval", "-z", "-hzx", "-oh",
+];
+
+/// Simple deterministic pseudo-random number generator (xorshift)
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % bound as u64) as usize
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Check each argument on its own, and generated argument vectors
+#[test]
+fn generated() {
+    let mut vectors: Vec<Vec<&str>> = ARG_POOL.iter().map(|&a| vec![a]).collect();
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..2000 {
+        let len = 1 + rng.next(6);
+        vectors.push((0..len).map(|_| ARG_POOL[rng.next(ARG_POOL.len())]).collect());
+    }
+
+    let mut compared = 0;
+    for args in &vectors {
+        if is_known_difference(args) {
+            continue;
+        }
+        assert_eq!(run_gong(args), run_glibc(args), "args: {:?}", args);
+        compared += 1;
+    }
+    // Guard against the allowlist silently excluding most of the generated cases
+    assert!(compared > vectors.len() / 2, "only {} of {} compared", compared, vectors.len());
+}
+
+/// Check that each known difference does actually exist, to keep the allowlist accurate
+#[test]
+fn known_differences() {
+    for difference in KNOWN_DIFFERENCES {
+        let args = [difference.example];
+        assert!((difference.matches)(difference.example), "{}", difference.description);
+        assert_ne!(run_gong(&args), run_glibc(&args), "{}", difference.description);
+    }
+}
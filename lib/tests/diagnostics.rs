@@ -0,0 +1,143 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Testing diagnostic rendering
+
+#[macro_use]
+extern crate gong;
+
+#[allow(unused_macros)]
+#[allow(dead_code)] //Mod shared across test crates
+#[macro_use]
+mod common;
+
+use std::collections::HashMap;
+use gong::diagnostics::*;
+use gong::options::*;
+use common::get_base;
+
+fn render(renderer: Renderer, args: &[&str]) -> String {
+    let analysis = get_base().process(args);
+    renderer.render(args, OptionsMode::Standard, &analysis)
+}
+
+/// Check plain rendering of a range of problems
+#[test]
+fn plain() {
+    let args = arg_list!("abc", "-hzx", "--fo", "--xyz=a", "--foo=bar", "with space", "--hah");
+    let expected = "\
+warning: unknown short option `z`
+    abc -hzx --fo --xyz=a --foo=bar 'with space' --hah
+          ^
+error: ambiguous option `--fo`
+    abc -hzx --fo --xyz=a --foo=bar 'with space' --hah
+               ^~
+warning: unknown option `--xyz`
+    abc -hzx --fo --xyz=a --foo=bar 'with space' --hah
+                    ^~~
+warning: option `--foo` does not take a value
+    abc -hzx --fo --xyz=a --foo=bar 'with space' --hah
+                                ^~~
+error: option `--hah` requires a value
+    abc -hzx --fo --xyz=a --foo=bar 'with space' --hah
+                                                   ^~~
+";
+    assert_eq!(render(Renderer::PLAIN, &args), expected);
+}
+
+/// Check multi-byte chars and nameless options
+#[test]
+fn multibyte() {
+    let args = arg_list!("--=x", "-❤Ɛ");
+    let expected = "\
+warning: option argument without a name
+    --=x -❤Ɛ
+      ^
+error: short option `Ɛ` requires a value
+    --=x -❤Ɛ
+           ^
+";
+    assert_eq!(render(Renderer::PLAIN, &args), expected);
+}
+
+/// Check invalid values are reported with the possible values, underlining the value
+#[test]
+fn invalid_value() {
+    let mut opts = get_base().to_extendible();
+    opts.long.push(gong_longopt!("color", true; possible_values: &["always", "never", "auto"]));
+    let opts = opts.as_fixed();
+    let args = arg_list!("--col=blue");
+    let analysis = opts.process(&args);
+    let expected = "\
+error: invalid value `blue` for option `--col` (possible values: always, never, auto)
+    --col=blue
+          ^~~~
+";
+    assert_eq!(Renderer::PLAIN.render(&args, OptionsMode::Standard, &analysis), expected);
+}
+
+/// Check deprecated options are reported with their replacement
+#[test]
+fn deprecated() {
+    let opts = gong_option_set_fixed!(
+        [ gong_longopt!("color", false; status: OptionStatus::Deprecated(Some("--colour"))) ],
+        [ gong_shortopt!('d', false; status: OptionStatus::Deprecated(None)) ]
+    );
+    let args = arg_list!("--col", "-d");
+    let analysis = opts.process(&args);
+    let expected = "\
+warning: option `--col` is deprecated, use `--colour` instead
+    --col -d
+      ^~~
+warning: short option `d` is deprecated
+    --col -d
+           ^
+";
+    assert_eq!(Renderer::PLAIN.render(&args, OptionsMode::Standard, &analysis), expected);
+}
+
+/// Check abbreviation use warnings
+#[test]
+fn abbreviated() {
+    let mut opts = get_base().clone();
+    opts.set_warn_abbreviations(true);
+    let args = arg_list!("--vers");
+    let analysis = opts.process(&args);
+    let expected = "\
+warning: option `--vers` is an abbreviation of `--version`
+    --vers
+      ^~~~
+";
+    assert_eq!(Renderer::PLAIN.render(&args, OptionsMode::Standard, &analysis), expected);
+}
+
+/// Check nothing is rendered without problems
+#[test]
+fn no_problems() {
+    assert_eq!(render(Renderer::PLAIN, &arg_list!("abc", "--foo")), "");
+}
+
+/// Check color, and that `NO_COLOR` is respected
+#[test]
+fn color() {
+    let mut env = HashMap::new();
+    assert_eq!(Renderer::from_env(&env), Renderer { color: true });
+    env.insert(String::from("NO_COLOR"), String::new());
+    assert_eq!(Renderer::from_env(&env), Renderer { color: true });
+    env.insert(String::from("NO_COLOR"), String::from("1"));
+    assert_eq!(Renderer::from_env(&env), Renderer { color: false });
+
+    let expected = "\
+\u{1b}[1;33mwarning\u{1b}[0m: unknown option `--xyz`
+    --xyz
+      \u{1b}[1;33m^~~\u{1b}[0m
+";
+    assert_eq!(render(Renderer { color: true }, &arg_list!("--xyz")), expected);
+}
@@ -0,0 +1,30 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Testing use of the construction macros imported by path (as in 2018+ edition crates), rather
+//! than with `#[macro_use]`, which requires any macros they use internally to be crate-qualified
+
+extern crate gong;
+
+use gong::{gong_option_set_checked, gong_longopt, gong_shortopt};
+use gong::options::OptionSet;
+
+static OPTS: OptionSet = gong_option_set_checked!(
+    [ gong_longopt!("help"), gong_longopt!("file", true) ],
+    [ gong_shortopt!('h') ]
+);
+
+#[test]
+fn imported() {
+    assert!(OPTS.is_valid());
+    assert_eq!(OPTS.long.len(), 2);
+    assert!(!OPTS.long[0].expects_data);
+    assert!(!OPTS.short[0].expects_data);
+}
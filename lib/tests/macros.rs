@@ -62,24 +62,51 @@ mod available_options {
         // Re-build it by hand for comparison
         let hand_built = OptionSet {
             long: &[
-                LongOption { name: "help", expects_data: false },
-                LongOption { name: "foo", expects_data: false },
-                LongOption { name: "version", expects_data: false },
-                LongOption { name: "foobar", expects_data: false },
-                LongOption { name: "hah", expects_data: true },
-                LongOption { name: "ábc", expects_data: false },
-                LongOption { name: "ƒƒ", expects_data: true },
+                LongOption { name: "help", expects_data: false, env: None, negatable: false,
+                    possible_values: &[], default: None, status: OptionStatus::Normal,
+                    allow_abbreviation: true, min_abbreviation: 0 },
+                LongOption { name: "foo", expects_data: false, env: None, negatable: false,
+                    possible_values: &[], default: None, status: OptionStatus::Normal,
+                    allow_abbreviation: true, min_abbreviation: 0 },
+                LongOption { name: "version", expects_data: false, env: None, negatable: false,
+                    possible_values: &[], default: None, status: OptionStatus::Normal,
+                    allow_abbreviation: true, min_abbreviation: 0 },
+                LongOption { name: "foobar", expects_data: false, env: None, negatable: false,
+                    possible_values: &[], default: None, status: OptionStatus::Normal,
+                    allow_abbreviation: true, min_abbreviation: 0 },
+                LongOption { name: "hah", expects_data: true, env: None, negatable: false,
+                    possible_values: &[], default: None, status: OptionStatus::Normal,
+                    allow_abbreviation: true, min_abbreviation: 0 },
+                LongOption { name: "ábc", expects_data: false, env: None, negatable: false,
+                    possible_values: &[], default: None, status: OptionStatus::Normal,
+                    allow_abbreviation: true, min_abbreviation: 0 },
+                LongOption { name: "ƒƒ", expects_data: true, env: None, negatable: false,
+                    possible_values: &[], default: None, status: OptionStatus::Normal,
+                    allow_abbreviation: true, min_abbreviation: 0 },
             ],
             short: &[
-                ShortOption { ch: 'h', expects_data: false },
-                ShortOption { ch: '❤', expects_data: false },
-                ShortOption { ch: 'x', expects_data: false },
-                ShortOption { ch: 'o', expects_data: true },
-                ShortOption { ch: '\u{030A}', expects_data: false },
-                ShortOption { ch: 'Ɛ', expects_data: true },
+                ShortOption { ch: 'h', expects_data: false, negatable: false,
+                    status: OptionStatus::Normal },
+                ShortOption { ch: '❤', expects_data: false, negatable: false,
+                    status: OptionStatus::Normal },
+                ShortOption { ch: 'x', expects_data: false, negatable: false,
+                    status: OptionStatus::Normal },
+                ShortOption { ch: 'o', expects_data: true, negatable: false,
+                    status: OptionStatus::Normal },
+                ShortOption { ch: '\u{030A}', expects_data: false, negatable: false,
+                    status: OptionStatus::Normal },
+                ShortOption { ch: 'Ɛ', expects_data: true, negatable: false,
+                    status: OptionStatus::Normal },
             ],
+            positionals: &[],
             mode: MODE_DEFAULT,
             allow_abbreviations: ABBR_SUP_DEFAULT,
+            ordering: ORDERING_DEFAULT,
+            normalization: Normalization::NONE,
+            plus_prefix: false,
+            negative_numbers: false,
+            pass_through: false,
+            warn_abbreviations: false,
         };
 
         assert_eq!(*macro_built, hand_built);
@@ -120,8 +147,15 @@ mod available_options {
         let cmp = OptionSet {
             long: &[],
             short: &[],
+            positionals: &[],
             mode: OptionsMode::Alternate,
             allow_abbreviations: false,
+            ordering: ORDERING_DEFAULT,
+            normalization: Normalization::NONE,
+            plus_prefix: false,
+            negative_numbers: false,
+            pass_through: false,
+            warn_abbreviations: false,
         };
         assert_eq!(opts, cmp);
         assert_eq!(opts_ex, cmp);
@@ -132,10 +166,30 @@ mod available_options {
         let cmp = OptionSet {
             long: &[],
             short: &[],
+            positionals: &[],
             mode: MODE_DEFAULT,
             allow_abbreviations: ABBR_SUP_DEFAULT,
+            ordering: ORDERING_DEFAULT,
+            normalization: Normalization::NONE,
+            plus_prefix: false,
+            negative_numbers: false,
+            pass_through: false,
+            warn_abbreviations: false,
         };
         assert_eq!(opts, cmp);
         assert_eq!(opts_ex, cmp);
     }
+
+    /// Check positional construction, with the maximum left to default
+    #[test]
+    fn positionals() {
+        assert_eq!(gong_positional!("FILE"), Positional { name: "FILE", min: 1, max: Some(1) });
+        assert_eq!(gong_positional!("FILE", 0), Positional { name: "FILE", min: 0, max: Some(1) });
+        assert_eq!(gong_positional!("FILES", 2),
+            Positional { name: "FILES", min: 2, max: Some(2) });
+
+        static POSITIONALS: &[Positional] = &[ gong_positional!("FILES", 2) ];
+        let opts = gong_option_set_fixed!([], []; positionals: POSITIONALS);
+        assert!(opts.is_valid());
+    }
 }
@@ -12,6 +12,9 @@
 //!
 //! Note, construction with macros is tested separately
 
+// Validity checks are compared with `assert_eq` for readability
+#![allow(clippy::bool_assert_comparison)]
+
 #[macro_use]
 extern crate gong;
 
@@ -22,7 +25,8 @@ mod common;
 
 use gong::analysis::*;
 use gong::options::*;
-use common::{Actual, Expected, check_result, MODE_DEFAULT, ABBR_SUP_DEFAULT};
+use common::{Actual, Expected, check_result, MODE_DEFAULT, ABBR_SUP_DEFAULT,
+    ORDERING_DEFAULT};
 
 /// Check basic valid construction methods
 #[test]
@@ -46,8 +50,15 @@ fn basic() {
             gong_shortopt!('o', true),
             gong_shortopt!('a', false),
         ],
+        positionals: vec![],
         mode: MODE_DEFAULT,
         allow_abbreviations: ABBR_SUP_DEFAULT,
+        ordering: ORDERING_DEFAULT,
+        normalization: Normalization::NONE,
+        plus_prefix: false,
+        negative_numbers: false,
+        pass_through: false,
+        warn_abbreviations: false,
     };
 
     assert_eq!(opts, expected);
@@ -94,8 +105,15 @@ fn set_types() {
             gong_shortopt!('o', true),
             gong_shortopt!('a', false),
         ],
+        positionals: &[],
         mode: MODE_DEFAULT,
         allow_abbreviations: ABBR_SUP_DEFAULT,
+        ordering: ORDERING_DEFAULT,
+        normalization: Normalization::NONE,
+        plus_prefix: false,
+        negative_numbers: false,
+        pass_through: false,
+        warn_abbreviations: false,
     };
 
     let opts_extendible = OptionSetEx {
@@ -109,8 +127,15 @@ fn set_types() {
             gong_shortopt!('o', true),
             gong_shortopt!('a', false),
         ],
+        positionals: vec![],
         mode: MODE_DEFAULT,
         allow_abbreviations: ABBR_SUP_DEFAULT,
+        ordering: ORDERING_DEFAULT,
+        normalization: Normalization::NONE,
+        plus_prefix: false,
+        negative_numbers: false,
+        pass_through: false,
+        warn_abbreviations: false,
     };
 
     // Check the two types can be compared
@@ -134,8 +159,15 @@ fn set_types() {
             gong_longopt!("blah", false),
         ],
         short: &[],
+        positionals: &[],
         mode: MODE_DEFAULT,
         allow_abbreviations: ABBR_SUP_DEFAULT,
+        ordering: ORDERING_DEFAULT,
+        normalization: Normalization::NONE,
+        plus_prefix: false,
+        negative_numbers: false,
+        pass_through: false,
+        warn_abbreviations: false,
     };
 
     let opts_extendible_2 = OptionSetEx {
@@ -143,8 +175,15 @@ fn set_types() {
             gong_longopt!("blah", false),
         ],
         short: vec![],
+        positionals: vec![],
         mode: MODE_DEFAULT,
         allow_abbreviations: ABBR_SUP_DEFAULT,
+        ordering: ORDERING_DEFAULT,
+        normalization: Normalization::NONE,
+        plus_prefix: false,
+        negative_numbers: false,
+        pass_through: false,
+        warn_abbreviations: false,
     };
 
     // Verify not equal
@@ -348,6 +387,28 @@ mod long_equals {
 /// generate (large) option sets (rare? VLC media player is one example, which dynamically builds an
 /// option set including options from plugins). An option set containing duplicates is thus
 /// considered invalid.
+/// A default value is only valid for a data taking option, and must be one of any possible values
+mod long_default {
+    use super::*;
+
+    #[test]
+    fn invalid_set() {
+        let opts = gong_option_set_fixed!(
+            [
+                gong_longopt!("foo", false; default: Some("x")),
+                gong_longopt!("bar", true; default: Some("x")),
+                gong_longopt!("baz", true; default: Some("x"), possible_values: &["y", "z"]),
+                gong_longopt!("qux", true; default: Some("z"), possible_values: &["y", "z"]),
+            ], []
+        );
+        assert!(!opts.is_valid());
+        assert_eq!(opts.validate(), Err(vec![
+            OptionFlaw::LongDefaultInvalid("foo"),
+            OptionFlaw::LongDefaultInvalid("baz"),
+        ]));
+    }
+}
+
 mod duplicates {
     use super::*;
 
@@ -386,4 +447,222 @@ mod duplicates {
             OptionFlaw::LongDup("bbb"),
         ]));
     }
+
+    /// Check duplicates are found per the normalization policy
+    #[test]
+    fn long_normalized() {
+        let mut opts = OptionSetEx::new(5, 0);
+        opts.add_long("dry-run")
+            .add_long("Dry-Run")
+            .add_long("dry_run")
+            .add_long("DRY_RUN")
+            .add_long("other");
+        assert!(opts.is_valid());
+
+        opts.set_normalization(Normalization { case: CaseFolding::Ascii, ..Normalization::NONE });
+        assert_eq!(opts.validate(), Err(vec![
+            OptionFlaw::LongDup("Dry-Run"),
+            OptionFlaw::LongDup("DRY_RUN"),
+        ]));
+
+        // Each normalized name reported only once
+        let normalization = Normalization {
+            case: CaseFolding::Ascii, fold_separators: true, ..Normalization::NONE
+        };
+        opts.set_normalization(normalization);
+        assert_eq!(opts.validate(), Err(vec![
+            OptionFlaw::LongDup("Dry-Run"),
+        ]));
+    }
+}
+
+/// Validation reports, with locations and lints
+mod report {
+    use super::*;
+
+    #[test]
+    fn clean() {
+        assert_eq!(common::get_base().validation_report(), vec![]);
+    }
+
+    /// Check problems are those of `validate`, in the same order, with locations
+    #[test]
+    fn flaws() {
+        let mut opts = OptionSetEx::new(0, 0);
+        opts.add_long("foo")
+            .add_existing_long(gong_longopt!("", false))
+            .add_existing_long(gong_longopt!("a=b", false))
+            .add_long("foo")
+            .add_existing_long(gong_longopt!("bar", false; default: Some("x")))
+            .add_existing_short(gong_shortopt!('-', false))
+            .add_short('1')
+            .add_short('1');
+        opts.positionals = vec![
+            gong_positional!("A", 0, None),
+            gong_positional!("", 2, Some(1)),
+            gong_positional!("A"),
+        ];
+
+        let report = opts.validation_report();
+        let flaws: Vec<OptionFlaw> = report.iter()
+            .filter_map(|f| match f.kind { FindingKind::Flaw(flaw) => Some(flaw), _ => None })
+            .collect();
+        assert_eq!(Err(flaws), opts.validate());
+        assert!(report.iter().all(|f| f.severity() == Severity::Error));
+
+        let locations: Vec<FindingLocation> = report.iter().map(|f| f.location).collect();
+        assert_eq!(locations, vec![
+            FindingLocation::Long(1, ""),
+            FindingLocation::Long(2, "a=b"),
+            FindingLocation::Long(4, "bar"),
+            FindingLocation::Short(0, '-'),
+            FindingLocation::Short(2, '1'),
+            FindingLocation::Long(3, "foo"),
+            FindingLocation::Positional(0, "A"),
+            FindingLocation::Positional(1, ""),
+            FindingLocation::Positional(1, ""),
+            FindingLocation::Positional(2, "A"),
+        ]);
+    }
+
+    #[test]
+    fn lints() {
+        let opts = gong_option_set_fixed!(
+            [
+                gong_longopt!("--foo"),
+                gong_longopt!("dry run"),
+                gong_longopt!("tab\t"),
+                gong_longopt!("zero\u{200b}width"),
+                gong_longopt!("раth"),         // Cyrillic `р`
+                gong_longopt!("ｗide"),        // Fullwidth `w`
+                gong_longopt!("en–dash"),      // En dash
+                gong_longopt!("-ах\u{feff}"), // Several, each reported
+            ],
+            [
+                gong_shortopt!(' '),
+                gong_shortopt!('а'),
+            ]
+        );
+        let report = opts.validation_report();
+        assert!(report.iter().all(|f| f.severity() == Severity::Lint));
+        assert!(opts.is_valid());
+        let lint = |location, lint| ValidationFinding { location, kind: FindingKind::Lint(lint) };
+        assert_eq!(report, vec![
+            lint(FindingLocation::Long(0, "--foo"), OptionLint::LeadingDash),
+            lint(FindingLocation::Long(1, "dry run"), OptionLint::Whitespace),
+            lint(FindingLocation::Long(2, "tab\t"), OptionLint::ControlChar),
+            lint(FindingLocation::Long(3, "zero\u{200b}width"), OptionLint::ZeroWidth('\u{200b}')),
+            lint(FindingLocation::Long(4, "раth"), OptionLint::Confusable('р', 'p')),
+            lint(FindingLocation::Long(5, "ｗide"), OptionLint::Confusable('ｗ', 'w')),
+            lint(FindingLocation::Long(6, "en–dash"), OptionLint::Confusable('–', '-')),
+            lint(FindingLocation::Long(7, "-ах\u{feff}"), OptionLint::LeadingDash),
+            lint(FindingLocation::Long(7, "-ах\u{feff}"), OptionLint::Confusable('а', 'a')),
+            lint(FindingLocation::Long(7, "-ах\u{feff}"), OptionLint::ZeroWidth('\u{feff}')),
+            lint(FindingLocation::Short(0, ' '), OptionLint::Whitespace),
+            lint(FindingLocation::Short(1, 'а'), OptionLint::Confusable('а', 'a')),
+        ]);
+    }
+
+    /// Check short options are reported as unused with modes without them
+    #[test]
+    fn short_unsupported() {
+        let mut opts = OptionSetEx::new(1, 1);
+        opts.add_long("foo").add_short('h');
+        assert_eq!(opts.validation_report(), vec![]);
+        for mode in &[OptionsMode::Alternate, OptionsMode::Dos] {
+            opts.set_mode(*mode);
+            assert_eq!(opts.validation_report(), vec![ValidationFinding {
+                location: FindingLocation::Short(0, 'h'),
+                kind: FindingKind::Lint(OptionLint::ShortUnsupported),
+            }]);
+        }
+    }
+
+    #[test]
+    fn display() {
+        let opts = gong_option_set_fixed!(
+            [ gong_longopt!(""), gong_longopt!("zero\u{200b}width") ],
+            [ gong_shortopt!('х') ]
+        );
+        let messages: Vec<String> = opts.validation_report().iter().map(|f| f.to_string())
+            .collect();
+        assert_eq!(messages, vec![
+            "error: long option (index 0): name is empty",
+            "lint: long option `zero\\u{200b}width` (index 1): name contains invisible character \
+             U+200B",
+            "lint: short option `х` (index 0): name contains `х` (U+0445), easily confused \
+             with `x`",
+        ]);
+    }
+}
+
+/// Compile-time validation gives the first flaw `validate` would list
+mod compile_time {
+    use super::*;
+
+    /// A set checked at compile time
+    static CHECKED: OptionSet = gong_option_set_checked!(
+        [ gong_longopt!("foo"), gong_longopt!("bar", true; default: Some("x")) ],
+        [ gong_shortopt!('a') ];
+        positionals: &[ gong_positional!("INPUT"), gong_positional!("FILE", 0, None) ]
+    );
+
+    /// Checks `first_flaw` against `validate`
+    fn check(opts: &OptionSet) {
+        let expected = opts.validate().err().map(|mut flaws| flaws.remove(0));
+        assert_eq!(opts.first_flaw(), expected);
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(CHECKED.first_flaw(), None);
+        assert!(CHECKED.is_valid());
+        check(common::get_base());
+    }
+
+    #[test]
+    fn each_flaw() {
+        check(&gong_option_set_fixed!([ gong_longopt!("a"), gong_longopt!("") ], []));
+        check(&gong_option_set_fixed!([ gong_longopt!("a=b") ], [ gong_shortopt!('-') ]));
+        check(&gong_option_set_fixed!([ gong_longopt!("a", false; default: Some("x")) ], []));
+        check(&gong_option_set_fixed!([ gong_longopt!("a", true; default: Some("x"),
+            possible_values: &["y"]) ], []));
+        check(&gong_option_set_fixed!([], [ gong_shortopt!('a'), gong_shortopt!('-') ]));
+        check(&gong_option_set_fixed!([], [ gong_shortopt!('1') ]; negative_numbers: true));
+        check(&gong_option_set_fixed!([], [ gong_shortopt!('1') ]));
+        check(&gong_option_set_fixed!([], [ gong_shortopt!('❤'), gong_shortopt!('❤') ]));
+        check(&gong_option_set_fixed!([ gong_longopt!("ƒoo"), gong_longopt!("ƒoo") ],
+            [ gong_shortopt!('a'), gong_shortopt!('a') ]));
+        check(&gong_option_set_fixed!([], []; positionals: &[ gong_positional!("") ]));
+        check(&gong_option_set_fixed!([], []; positionals: &[ gong_positional!("A", 2, Some(1)) ]));
+        check(&gong_option_set_fixed!([], []; positionals: &[
+            gong_positional!("A", 0, None), gong_positional!("B") ]));
+        check(&gong_option_set_fixed!([], []; positionals: &[
+            gong_positional!("A"), gong_positional!("B"), gong_positional!("A") ]));
+    }
+
+    /// Check duplicates are found per the normalization policy and mode
+    #[test]
+    fn normalized() {
+        let mut opts = OptionSetEx::new(5, 0);
+        opts.add_long("dry-run")
+            .add_long("Dry-Run")
+            .add_long("dry_run")
+            .add_long("other");
+        check(&opts.as_fixed());
+        opts.set_normalization(Normalization { fold_separators: true, ..Normalization::NONE });
+        check(&opts.as_fixed());
+        opts.set_normalization(Normalization { case: CaseFolding::Unicode, ..Normalization::NONE });
+        check(&opts.as_fixed());
+        opts.set_normalization(Normalization::NONE).set_mode(OptionsMode::Dos);
+        check(&opts.as_fixed());
+        assert_eq!(opts.as_fixed().first_flaw(), Some(OptionFlaw::LongDup("Dry-Run")));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid option set: duplicate short option")]
+    fn assert_valid() {
+        let _ = gong_option_set_fixed!([], [ gong_shortopt!('a'), gong_shortopt!('a') ])
+            .assert_valid();
+    }
 }
@@ -0,0 +1,199 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Testing positional argument declaration, binding and usage synopsis generation
+
+#[macro_use]
+extern crate gong;
+
+#[allow(unused_macros)]
+#[allow(dead_code)] //Mod shared across test crates
+#[macro_use]
+mod common;
+
+use gong::analysis::*;
+use gong::options::*;
+use common::get_base;
+
+/// A base set with an input, an optional output, and trailing variadic extras
+static POS_OPTS: OptionSet = gong_option_set_fixed!(
+    [ gong_longopt!("foo") ], [ gong_shortopt!('o', true) ];
+    positionals: &[
+        gong_positional!("INPUT"),
+        gong_positional!("OUTPUT", 0, Some(1)),
+        gong_positional!("EXTRA", 0, None),
+    ]
+);
+
+/// Check macro, method and hand built positionals compare equal
+#[test]
+fn construction() {
+    let mut opts = OptionSetEx::new(1, 1);
+    opts.add_long("foo")
+        .add_short_data('o')
+        .add_positional("INPUT")
+        .add_optional_positional("OUTPUT")
+        .add_variadic_positional("EXTRA", 0);
+    assert_eq!(POS_OPTS, opts);
+
+    let hand_built = vec![
+        Positional { name: "INPUT", min: 1, max: Some(1) },
+        Positional { name: "OUTPUT", min: 0, max: Some(1) },
+        Positional { name: "EXTRA", min: 0, max: None },
+    ];
+    assert_eq!(POS_OPTS.positionals, &hand_built[..]);
+
+    let opts_ex = gong_option_set!(
+        vec![ gong_longopt!("foo") ], vec![ gong_shortopt!('o', true) ];
+        positionals: hand_built
+    );
+    assert_eq!(POS_OPTS, opts_ex);
+    assert!(POS_OPTS.is_valid());
+
+    // Maximum left to default
+    assert_eq!(gong_positional!("OUTPUT", 0), POS_OPTS.positionals[1]);
+}
+
+/// Check `EMPTY` matches an empty default extendible set
+#[test]
+fn empty_const() {
+    assert_eq!(OptionSet::EMPTY, OptionSetEx::default());
+}
+
+/// Check binding of *non-options*, ignoring options and consumed data arguments
+#[test]
+fn binding() {
+    let args = arg_list!("in", "--foo", "-o", "data", "out", "a", "--", "-b");
+    let analysis = POS_OPTS.process(&args);
+    let expected = PositionalAnalysis {
+        items: vec![
+            PositionalItem::Bound { i: 0, n: "INPUT", v: "in" },
+            PositionalItem::Bound { i: 4, n: "OUTPUT", v: "out" },
+            PositionalItem::Bound { i: 5, n: "EXTRA", v: "a" },
+            PositionalItem::Bound { i: 7, n: "EXTRA", v: "-b" },
+        ],
+        error: false,
+    };
+    assert_eq!(analysis.bind_positionals(POS_OPTS.positionals), expected);
+
+    // Optional positionals can be left out
+    let args = arg_list!("in");
+    let analysis = POS_OPTS.process(&args);
+    let expected = PositionalAnalysis {
+        items: vec![
+            PositionalItem::Bound { i: 0, n: "INPUT", v: "in" },
+        ],
+        error: false,
+    };
+    assert_eq!(analysis.bind_positionals(POS_OPTS.positionals), expected);
+}
+
+/// Check missing positionals are reported
+#[test]
+fn missing() {
+    let args = arg_list!("--foo");
+    let analysis = POS_OPTS.process(&args);
+    let expected = PositionalAnalysis {
+        items: vec![
+            PositionalItem::Missing("INPUT"),
+        ],
+        error: true,
+    };
+    assert_eq!(analysis.bind_positionals(POS_OPTS.positionals), expected);
+
+    // Minimums are satisfied before spare arguments are handed out to earlier positionals
+    let positionals = [
+        gong_positional!("A", 0, Some(2)),
+        gong_positional!("B", 2, Some(2)),
+    ];
+    let args = arg_list!("x", "y");
+    let analysis = get_base().process(&args);
+    let expected = PositionalAnalysis {
+        items: vec![
+            PositionalItem::Bound { i: 0, n: "B", v: "x" },
+            PositionalItem::Bound { i: 1, n: "B", v: "y" },
+        ],
+        error: false,
+    };
+    assert_eq!(analysis.bind_positionals(&positionals), expected);
+
+    let args = arg_list!("x");
+    let analysis = get_base().process(&args);
+    let expected = PositionalAnalysis {
+        items: vec![
+            PositionalItem::Bound { i: 0, n: "B", v: "x" },
+            PositionalItem::Missing("B"),
+        ],
+        error: true,
+    };
+    assert_eq!(analysis.bind_positionals(&positionals), expected);
+}
+
+/// Check excess *non-options* are reported
+#[test]
+fn unexpected() {
+    let positionals = [ gong_positional!("INPUT") ];
+    let args = arg_list!("a", "--foo", "b", "c");
+    let analysis = get_base().process(&args);
+    let expected = PositionalAnalysis {
+        items: vec![
+            PositionalItem::Bound { i: 0, n: "INPUT", v: "a" },
+            PositionalItem::Unexpected(2, "b"),
+            PositionalItem::Unexpected(3, "c"),
+        ],
+        error: true,
+    };
+    assert_eq!(analysis.bind_positionals(&positionals), expected);
+
+    // With none declared, all are unexpected
+    let args = arg_list!("a");
+    let analysis = get_base().process(&args);
+    let expected = PositionalAnalysis {
+        items: vec![ PositionalItem::Unexpected(0, "a") ],
+        error: true,
+    };
+    assert_eq!(analysis.bind_positionals(&[]), expected);
+}
+
+/// Check validation of positionals
+#[test]
+fn validation() {
+    let opts = gong_option_set_fixed!([], [];
+        positionals: &[
+            gong_positional!("A", 0, None),
+            gong_positional!("B", 2, Some(1)),
+            gong_positional!(""),
+            gong_positional!("B"),
+        ]
+    );
+    assert!(!opts.is_valid());
+    assert_eq!(opts.validate(), Err(vec![
+        OptionFlaw::PositionalUnboundedNotLast("A"),
+        OptionFlaw::PositionalMaxBelowMin("B"),
+        OptionFlaw::PositionalEmpty,
+        OptionFlaw::PositionalDup("B"),
+    ]));
+}
+
+/// Check usage synopsis generation
+#[test]
+fn synopsis() {
+    assert_eq!(POS_OPTS.synopsis("prog"), "prog [OPTIONS] INPUT [OUTPUT] [EXTRA]...");
+
+    let opts = gong_option_set_fixed!([], [];
+        positionals: &[
+            gong_positional!("A", 2, Some(2)),
+            gong_positional!("B", 0, Some(3)),
+            gong_positional!("C", 1, None),
+        ]
+    );
+    assert_eq!(opts.synopsis("prog"), "prog A A [B]... C...");
+    assert_eq!(opts.to_extendible().synopsis("prog"), "prog A A [B]... C...");
+}
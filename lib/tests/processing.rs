@@ -8,6 +8,9 @@
 // <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
 // respectively.
 
+// Construction macros cannot know when every attribute has been given
+#![allow(clippy::needless_update)]
+
 #[macro_use]
 extern crate gong;
 
@@ -371,6 +374,87 @@ mod abbreviations {
         );
         check_result(&Actual(opts.process(&args)), &expected);
     }
+
+    /// Test per-option control, with options that cannot be abbreviated, or only down to a minimum
+    /// length, not counting as candidates otherwise
+    #[test]
+    fn per_option() {
+        let args = arg_list!(
+            "--del",        // Only `delete-all`, which cannot be abbreviated
+            "--de",         // Too short for `debug` (thus not ambiguous)
+            "--delete",     // Same as `--del`
+            "--delete-all", // Exact
+            "--verb",       // Fine
+        );
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, UnknownLong, "del"),
+                expected_item!(1, UnknownLong, "de"),
+                expected_item!(2, UnknownLong, "delete"),
+                expected_item!(3, Long, "delete-all"),
+                expected_item!(4, Long, "verbose"),
+            ]
+        );
+        let opts = gong_option_set_fixed!(
+            [
+                gong_longopt!("delete-all", false; allow_abbreviation: false),
+                gong_longopt!("debug", false; min_abbreviation: 3),
+                gong_longopt!("verbose"),
+            ],
+            []
+        );
+        check_result(&Actual(opts.process(&args)), &expected);
+
+        let args = arg_list!("--deb", "--d"); // At, and below, the minimum for `debug`
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, Long, "debug"),
+                expected_item!(1, UnknownLong, "d"),
+            ]
+        );
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Test warnings of abbreviation use
+    #[test]
+    fn warnings() {
+        let args = arg_list!("--foo", "--foob", "--hah=a", "--ha", "b", "--fo");
+        let expected = expected!(
+            error: true,
+            warn: true,
+            [
+                expected_item!(0, Long, "foo"),
+                expected_item!(1, Long, "foobar"),
+                expected_item!(1, LongAbbreviated, "foobar"),
+                expected_item!(2, LongWithData, "hah", "a", DataLocation::SameArg),
+                expected_item!(3, LongWithData, "hah", "b", DataLocation::NextArg),
+                expected_item!(3, LongAbbreviated, "hah"),
+                expected_item!(5, AmbiguousLong, "fo"),
+            ]
+        );
+        let mut opts = get_base().clone();
+        opts.set_warn_abbreviations(true);
+        check_result(&Actual(opts.process(&args)), &expected);
+
+        // Including with the DOS mode polarity suffixes (but not the help alias)
+        let args = arg_list!("/fooba+", "/foobar-", "/?");
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, Long, "foobar"),
+                expected_item!(0, LongAbbreviated, "foobar"),
+                expected_item!(1, LongNegated, "foobar"),
+                expected_item!(2, Long, "help"),
+            ]
+        );
+        opts.set_mode(OptionsMode::Dos);
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -906,3 +990,1096 @@ mod alt_mode {
         check_result(&Actual(opts.process(&args)), &expected);
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Ordering policies
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+mod ordering {
+    use super::*;
+
+    /// Check stopping at the first *non-option* (POSIX behaviour)
+    #[test]
+    fn stop_at_first() {
+        let args = arg_list!("--foo", "-o", "data", "cmd", "--help", "-x", "--", "arg");
+        let expected = expected!(
+            error: false,
+            warn: false,
+            [
+                expected_item!(0, Long, "foo"),
+                expected_item!(1, ShortWithData, 'o', "data", DataLocation::NextArg),
+                expected_item!(3, NonOption, "cmd"),
+                expected_item!(4, NonOption, "--help"),
+                expected_item!(5, NonOption, "-x"),
+                expected_item!(6, NonOption, "--"),
+                expected_item!(7, NonOption, "arg"),
+            ]
+        );
+        let mut opts = get_base().clone();
+        opts.set_ordering(OrderingPolicy::StopAtNonOption(1));
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check stopping at the Nth *non-option*, and with a limit of zero
+    #[test]
+    fn stop_at_nth() {
+        let args = arg_list!("a", "--foo", "b", "-x");
+        let expected = expected!(
+            error: false,
+            warn: false,
+            [
+                expected_item!(0, NonOption, "a"),
+                expected_item!(1, Long, "foo"),
+                expected_item!(2, NonOption, "b"),
+                expected_item!(3, NonOption, "-x"),
+            ]
+        );
+        let mut opts = get_base().clone();
+        opts.set_ordering(OrderingPolicy::StopAtNonOption(2));
+        check_result(&Actual(opts.process(&args)), &expected);
+
+        let args = arg_list!("--foo", "a");
+        let expected = expected!(
+            error: false,
+            warn: false,
+            [
+                expected_item!(0, NonOption, "--foo"),
+                expected_item!(1, NonOption, "a"),
+            ]
+        );
+        opts.set_ordering(OrderingPolicy::StopAtNonOption(0));
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check an early terminator before the limit is reached still works as normal
+    #[test]
+    fn stop_with_early_term() {
+        let args = arg_list!("--foo", "--", "--help");
+        let expected = expected!(
+            error: false,
+            warn: false,
+            [
+                expected_item!(0, Long, "foo"),
+                expected_item!(1, EarlyTerminator),
+                expected_item!(2, NonOption, "--help"),
+            ]
+        );
+        let mut opts = get_base().clone();
+        opts.set_ordering(OrderingPolicy::StopAtNonOption(1));
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check late options are flagged, but still processed
+    #[test]
+    fn warn_late() {
+        let args = arg_list!("--foo", "a", "--hah", "b", "-xo", "c", "--bogus", "--", "--help");
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, Long, "foo"),
+                expected_item!(1, NonOption, "a"),
+                expected_item!(2, LateOption),
+                expected_item!(2, LongWithData, "hah", "b", DataLocation::NextArg),
+                expected_item!(4, LateOption),
+                expected_item!(4, Short, 'x'),
+                expected_item!(4, ShortWithData, 'o', "c", DataLocation::NextArg),
+                expected_item!(6, LateOption),
+                expected_item!(6, UnknownLong, "bogus"),
+                expected_item!(7, EarlyTerminator),
+                expected_item!(8, NonOption, "--help"),
+            ]
+        );
+        let mut opts = get_base().clone();
+        opts.set_ordering(OrderingPolicy::WarnLateOptions);
+        check_result(&Actual(opts.process(&args)), &expected);
+
+        // No warning without a preceding *non-option*
+        let args = arg_list!("--foo", "-x", "a");
+        let expected = expected!(
+            error: false,
+            warn: false,
+            [
+                expected_item!(0, Long, "foo"),
+                expected_item!(1, Short, 'x'),
+                expected_item!(2, NonOption, "a"),
+            ]
+        );
+        check_result(&Actual(opts.process(&args)), &expected);
+
+        // No warning for ignored legacy options, which give no items
+        let mut opts = get_base().to_extendible();
+        opts.long.push(gong_longopt!("old", true; status: OptionStatus::Legacy));
+        opts.set_ordering(OrderingPolicy::WarnLateOptions);
+        let args = arg_list!("a", "--old", "x", "--foo");
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, NonOption, "a"),
+                expected_item!(3, LateOption),
+                expected_item!(3, Long, "foo"),
+            ]
+        );
+        check_result(&Actual(opts.as_fixed().process(&args)), &expected);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Name normalization
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+mod normalization {
+    use super::*;
+
+    /// Check case folding, with exact and abbreviated matches
+    #[test]
+    fn case_folding() {
+        let args = arg_list!("--FOO", "--Foob", "--VERS", "--ƑƑ=x", "--Hah=Data", "-H");
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, Long, "foo"),
+                expected_item!(1, Long, "foobar"),
+                expected_item!(2, Long, "version"),
+                expected_item!(3, UnknownLong, "ƑƑ"),
+                expected_item!(4, LongWithData, "hah", "Data", DataLocation::SameArg),
+                expected_item!(5, UnknownShort, 'H'),
+            ]
+        );
+        let mut opts = get_base().clone();
+        opts.set_normalization(Normalization { case: CaseFolding::Ascii, ..Normalization::NONE });
+        check_result(&Actual(opts.process(&args)), &expected);
+
+        // Unicode folding also covers non-ASCII names
+        let args = arg_list!("--ƑƑ=x", "--FOO");
+        let expected = expected!(
+            error: false,
+            warn: false,
+            [
+                expected_item!(0, LongWithData, "ƒƒ", "x", DataLocation::SameArg),
+                expected_item!(1, Long, "foo"),
+            ]
+        );
+        opts.set_normalization(Normalization { case: CaseFolding::Unicode, ..Normalization::NONE });
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check dash/underscore folding, along with ambiguity
+    #[test]
+    fn separators() {
+        let args = arg_list!("--dry_run", "--Dry-Run", "--dry_", "--dry_r", "--no_op");
+        let expected = expected!(
+            error: true,
+            warn: true,
+            [
+                expected_item!(0, Long, "dry-run"),
+                expected_item!(1, UnknownLong, "Dry-Run"),
+                expected_item!(2, AmbiguousLong, "dry_"),
+                expected_item!(3, Long, "dry-run"),
+                expected_item!(4, Long, "no_op"),
+            ]
+        );
+        let mut opts = gong_option_set!(
+            vec![ gong_longopt!("dry-run"), gong_longopt!("dry-check"), gong_longopt!("no_op") ],
+            vec![]
+        );
+        let normalization = Normalization {
+            case: CaseFolding::None, fold_separators: true, ..Normalization::NONE
+        };
+        opts.set_normalization(normalization);
+        assert!(opts.is_valid());
+        check_result(&Actual(opts.process(&args)), &expected);
+
+        // Without normalization
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, UnknownLong, "dry_run"),
+                expected_item!(1, UnknownLong, "Dry-Run"),
+                expected_item!(2, UnknownLong, "dry_"),
+                expected_item!(3, UnknownLong, "dry_r"),
+                expected_item!(4, Long, "no_op"),
+            ]
+        );
+        opts.set_normalization(Normalization::NONE);
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+}
+
+/// Unicode normalization (requires the `unicode-normalization` feature)
+#[cfg(feature = "unicode-normalization")]
+mod unicode_normalization {
+    use super::*;
+
+    /// Check canonically equivalent forms match, reporting the declared form
+    #[test]
+    fn nfc() {
+        // Declared name uses a combining accent; input uses precomposed, and vice versa
+        let args = arg_list!("--\u{E1}bc", "--a\u{301}b", "--f\u{F6}\u{F6}", "--fo\u{308}o\u{308}");
+        let expected = expected!(
+            error: false,
+            warn: false,
+            [
+                expected_item!(0, Long, "a\u{301}bc"),
+                expected_item!(1, Long, "a\u{301}bc"),
+                expected_item!(2, Long, "fo\u{308}o\u{308}"),
+                expected_item!(3, Long, "fo\u{308}o\u{308}"),
+            ]
+        );
+        let opts = gong_option_set_fixed!(
+            [ gong_longopt!("a\u{301}bc"), gong_longopt!("fo\u{308}o\u{308}") ], [];
+            normalization: Normalization { unicode: UnicodeForm::Nfc, ..Normalization::NONE }
+        );
+        check_result(&Actual(opts.process(&args)), &expected);
+
+        // Without normalization, they differ
+        let args = arg_list!("--\u{E1}bc");
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, UnknownLong, "\u{E1}bc"),
+            ]
+        );
+        check_result(&Actual(get_base().process(&args)), &expected);
+    }
+
+    /// Check compatibility equivalent forms match only with NFKC
+    #[test]
+    fn nfkc() {
+        let args = arg_list!("--\u{FB01}le");
+        let mut opts = gong_option_set!(vec![ gong_longopt!("file") ], vec![]);
+        opts.set_normalization(Normalization { unicode: UnicodeForm::Nfc, ..Normalization::NONE });
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, UnknownLong, "\u{FB01}le"),
+            ]
+        );
+        check_result(&Actual(opts.process(&args)), &expected);
+
+        opts.set_normalization(Normalization { unicode: UnicodeForm::Nfkc, ..Normalization::NONE });
+        let expected = expected!(
+            error: false,
+            warn: false,
+            [
+                expected_item!(0, Long, "file"),
+            ]
+        );
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check duplicate detection uses the normalized form
+    #[test]
+    fn duplicates() {
+        let opts = gong_option_set_fixed!(
+            [ gong_longopt!("f\u{F6}\u{F6}"), gong_longopt!("fo\u{308}o\u{308}") ], [];
+            normalization: Normalization { unicode: UnicodeForm::Nfc, ..Normalization::NONE }
+        );
+        assert_eq!(opts.validate(), Err(vec![ OptionFlaw::LongDup("fo\u{308}o\u{308}") ]));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// DOS-mode option processing
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+mod dos_mode {
+    use super::*;
+
+    /// Check a range of inputs
+    #[test]
+    fn basic() {
+        let args = arg_list!(
+            "abc",          // Non-option
+            "/help",        // Known option
+            "/FOO",         // Known option, different case
+            "/Vers",        // Abbreviated, different case
+            "/fo",          // Ambiguous
+            "/xyz",         // Unknown
+            "-help",        // Non-option in this mode
+            "--help",       // Non-option in this mode
+            "/",            // Non-option
+            "/:x",          // No name
+            "--",           // Early terminator
+            "/help",        // Non-option
+        );
+        let expected = expected!(
+            error: true,
+            warn: true,
+            [
+                expected_item!(0, NonOption, "abc"),
+                expected_item!(1, Long, "help"),
+                expected_item!(2, Long, "foo"),
+                expected_item!(3, Long, "version"),
+                expected_item!(4, AmbiguousLong, "fo"),
+                expected_item!(5, UnknownLong, "xyz"),
+                expected_item!(6, NonOption, "-help"),
+                expected_item!(7, NonOption, "--help"),
+                expected_item!(8, NonOption, "/"),
+                expected_item!(9, LongWithNoName),
+                expected_item!(10, EarlyTerminator),
+                expected_item!(11, NonOption, "/help"),
+            ]
+        );
+        let mut opts = get_base().clone();
+        opts.set_mode(OptionsMode::Dos);
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check data separators
+    #[test]
+    fn data() {
+        let args = arg_list!("/hah:a", "/HAH=b", "/hah:c=d", "/hah=e:f", "/hah", "g", "/foo:h");
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, LongWithData, "hah", "a", DataLocation::SameArg),
+                expected_item!(1, LongWithData, "hah", "b", DataLocation::SameArg),
+                expected_item!(2, LongWithData, "hah", "c=d", DataLocation::SameArg),
+                expected_item!(3, LongWithData, "hah", "e:f", DataLocation::SameArg),
+                expected_item!(4, LongWithData, "hah", "g", DataLocation::NextArg),
+                expected_item!(6, LongWithUnexpectedData, "foo", "h"),
+            ]
+        );
+        let mut opts = get_base().clone();
+        opts.set_mode(OptionsMode::Dos);
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check polarity suffixes, and `/?`
+    #[test]
+    fn polarity_and_help() {
+        let args = arg_list!("/foo+", "/Foo-", "/vers-", "/hah-", "x", "/?", "/+", "/c++");
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, Long, "foo"),
+                expected_item!(1, LongNegated, "foo"),
+                expected_item!(2, LongNegated, "version"),
+                expected_item!(3, UnknownLong, "hah-"),
+                expected_item!(4, NonOption, "x"),
+                expected_item!(5, Long, "help"),
+                expected_item!(6, UnknownLong, "+"),
+                expected_item!(7, Long, "c++"),
+            ]
+        );
+        let mut opts = get_base().to_extendible();
+        opts.set_mode(OptionsMode::Dos);
+        opts.add_long("c++");
+        check_result(&Actual(opts.process(&args)), &expected);
+
+        // An option actually named `?` is preferred
+        let args = arg_list!("/?");
+        let expected = expected!(
+            error: false,
+            warn: false,
+            [
+                expected_item!(0, Long, "?"),
+            ]
+        );
+        opts.add_long("?");
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check duplicates differing only in case are found
+    #[test]
+    fn case_duplicates() {
+        let mut opts = gong_option_set!(vec![ gong_longopt!("foo"), gong_longopt!("FOO") ], vec![]);
+        assert!(opts.is_valid());
+        opts.set_mode(OptionsMode::Dos);
+        assert_eq!(opts.validate(), Err(vec![ OptionFlaw::LongDup("FOO") ]));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Plus prefixed (negated) options
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+mod plus_prefix {
+    use super::*;
+
+    /// A set with some negatable options
+    static PLUS_OPTS: OptionSet = gong_option_set_fixed!(
+        [
+            gong_longopt!("verbose", false; negatable: true),
+            gong_longopt!("version"),
+            gong_longopt!("output", true; negatable: true),
+        ],
+        [
+            gong_shortopt!('x', false; negatable: true),
+            gong_shortopt!('e', false; negatable: true),
+            gong_shortopt!('h'),
+            gong_shortopt!('o', true; negatable: true),
+        ];
+        plus_prefix: true
+    );
+
+    /// Check macro and method built sets compare equal
+    #[test]
+    fn construction() {
+        let mut opts = OptionSetEx::new(3, 4);
+        opts.add_long_negatable("verbose")
+            .add_long("version")
+            .add_existing_long(LongOption { negatable: true, ..gong_longopt!("output", true) })
+            .add_short_negatable('x')
+            .add_short_negatable('e')
+            .add_short('h')
+            .add_existing_short(ShortOption { negatable: true, ..gong_shortopt!('o', true) })
+            .set_plus_prefix(true);
+        assert_eq!(PLUS_OPTS, opts);
+    }
+
+    /// Check short options
+    #[test]
+    fn short() {
+        let args = arg_list!("-x", "+x", "+xe", "+xh", "+o", "+z", "+", "++", "-o", "+e");
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, Short, 'x'),
+                expected_item!(1, ShortNegated, 'x'),
+                expected_item!(2, ShortNegated, 'x'),
+                expected_item!(2, ShortNegated, 'e'),
+                expected_item!(3, ShortNegated, 'x'),
+                expected_item!(3, ShortNotNegatable, 'h'),
+                expected_item!(4, ShortNotNegatable, 'o'),
+                expected_item!(5, UnknownShort, 'z'),
+                expected_item!(6, NonOption, "+"),
+                expected_item!(7, NonOption, "++"),
+                expected_item!(8, ShortWithData, 'o', "+e", DataLocation::NextArg),
+            ]
+        );
+        check_result(&Actual(PLUS_OPTS.process(&args)), &expected);
+    }
+
+    /// Check long options
+    #[test]
+    fn long() {
+        let args = arg_list!("++verbose", "++verb", "++version", "++vers", "++output",
+            "++verbose=x", "++verbose=", "++=a", "++foo", "++ver");
+        let expected = expected!(
+            error: true,
+            warn: true,
+            [
+                expected_item!(0, LongNegated, "verbose"),
+                expected_item!(1, LongNegated, "verbose"),
+                expected_item!(2, LongNotNegatable, "version"),
+                expected_item!(3, LongNotNegatable, "version"),
+                expected_item!(4, LongNotNegatable, "output"),
+                expected_item!(5, LongWithUnexpectedData, "verbose", "x"),
+                expected_item!(6, LongNegated, "verbose"),
+                expected_item!(7, LongWithNoName),
+                expected_item!(8, UnknownLong, "foo"),
+                expected_item!(9, AmbiguousLong, "ver"),
+            ]
+        );
+        check_result(&Actual(PLUS_OPTS.process(&args)), &expected);
+    }
+
+    /// Check alternate mode, where a single plus is the long prefix
+    #[test]
+    fn alt_mode() {
+        let args = arg_list!("+verbose", "-verbose", "++verbose");
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, LongNegated, "verbose"),
+                expected_item!(1, Long, "verbose"),
+                expected_item!(2, UnknownLong, "+verbose"),
+            ]
+        );
+        let mut opts = PLUS_OPTS.clone();
+        opts.set_mode(OptionsMode::Alternate);
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check plus prefixed arguments are *non-options* when not enabled, or after an early
+    /// terminator
+    #[test]
+    fn disabled() {
+        let args = arg_list!("+x", "++verbose");
+        let expected = expected!(
+            error: false,
+            warn: false,
+            [
+                expected_item!(0, NonOption, "+x"),
+                expected_item!(1, NonOption, "++verbose"),
+            ]
+        );
+        let mut opts = PLUS_OPTS.clone();
+        opts.set_plus_prefix(false);
+        check_result(&Actual(opts.process(&args)), &expected);
+
+        let args = arg_list!("--", "+x");
+        let expected = expected!(
+            error: false,
+            warn: false,
+            [
+                expected_item!(0, EarlyTerminator),
+                expected_item!(1, NonOption, "+x"),
+            ]
+        );
+        check_result(&Actual(PLUS_OPTS.process(&args)), &expected);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Long-only mode
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+mod long_only {
+    use super::*;
+
+    /// Check a range of inputs
+    #[test]
+    fn basic() {
+        let args = arg_list!(
+            "-h",           // Single char, matches short, taken as short
+            "-help",        // Long
+            "-hah=x",       // Long, with data
+            "-vers",        // Long, abbreviated
+            "-he",          // Long, abbreviated (wins over short option set)
+            "-fo",          // Long, ambiguous (no fallback)
+            "-xh",          // No long match, shorts
+            "-ofile",       // No long match, short with data in same arg
+            "-zz",          // No long match, first char not a short, so unknown long
+            "--foo",        // Long, double dash as standard
+            "-o",           // Single char, short with data in next arg
+            "next",         // Data
+            "-",            // Non-option
+            "--",           // Early terminator
+            "-help",        // Non-option
+        );
+        let expected = expected!(
+            error: true,
+            warn: true,
+            [
+                expected_item!(0, Short, 'h'),
+                expected_item!(1, Long, "help"),
+                expected_item!(2, LongWithData, "hah", "x", DataLocation::SameArg),
+                expected_item!(3, Long, "version"),
+                expected_item!(4, Long, "help"),
+                expected_item!(5, AmbiguousLong, "fo"),
+                expected_item!(6, Short, 'x'),
+                expected_item!(6, Short, 'h'),
+                expected_item!(7, ShortWithData, 'o', "file", DataLocation::SameArg),
+                expected_item!(8, UnknownLong, "zz"),
+                expected_item!(9, Long, "foo"),
+                expected_item!(10, ShortWithData, 'o', "next", DataLocation::NextArg),
+                expected_item!(12, NonOption, "-"),
+                expected_item!(13, EarlyTerminator),
+                expected_item!(14, NonOption, "-help"),
+            ]
+        );
+        let mut opts = get_base().clone();
+        opts.set_mode(OptionsMode::LongOnly);
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check fallback to shorts where abbreviations are disabled
+    #[test]
+    fn no_abbreviations() {
+        let args = arg_list!("-he", "-help");
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, Short, 'h'),
+                expected_item!(0, UnknownShort, 'e'),
+                expected_item!(1, Long, "help"),
+            ]
+        );
+        let mut opts = get_base().clone();
+        opts.set_mode(OptionsMode::LongOnly)
+            .set_allow_abbreviations(false);
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Pass-through
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+mod pass_through {
+    use super::*;
+
+    /// Check a range of inputs
+    #[test]
+    fn basic() {
+        let args = arg_list!(
+            "abc",          // Non-option
+            "--foo",        // Known
+            "--xyz=a",      // Unknown, with data in same arg
+            "--xyz",        // Unknown
+            "val",          // Data for the unknown? (Non-option)
+            "--fo",         // Ambiguous, not passed through
+            "-hx",          // Known shorts
+            "-hzx",         // Set with unknown, passed through whole
+            "-xozz",        // Set with data, not passed through
+            "-z",           // Unknown
+            "--",           // Early terminator
+            "--xyz",        // Non-option
+        );
+        let expected = expected!(
+            error: true,
+            warn: false,
+            [
+                expected_item!(0, NonOption, "abc"),
+                expected_item!(1, Long, "foo"),
+                expected_item!(2, PassThrough, "--xyz=a"),
+                expected_item!(3, PassThrough, "--xyz"),
+                expected_item!(4, NonOption, "val"),
+                expected_item!(5, AmbiguousLong, "fo"),
+                expected_item!(6, Short, 'h'),
+                expected_item!(6, Short, 'x'),
+                expected_item!(7, PassThrough, "-hzx"),
+                expected_item!(8, Short, 'x'),
+                expected_item!(8, ShortWithData, 'o', "zz", DataLocation::SameArg),
+                expected_item!(9, PassThrough, "-z"),
+                expected_item!(10, EarlyTerminator),
+                expected_item!(11, NonOption, "--xyz"),
+            ]
+        );
+        let mut opts = get_base().clone();
+        opts.set_pass_through(true);
+        let analysis = opts.process(&args);
+        assert_eq!(analysis.leftover_args(),
+            vec![ "abc", "--xyz=a", "--xyz", "val", "-hzx", "-z", "--xyz" ]);
+        check_result(&Actual(analysis), &expected);
+    }
+
+    /// Check negated forms
+    #[test]
+    fn negated() {
+        let args = arg_list!("++xyz", "+hz", "+h");
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, PassThrough, "++xyz"),
+                expected_item!(1, PassThrough, "+hz"),
+                expected_item!(2, ShortNotNegatable, 'h'),
+            ]
+        );
+        let mut opts = get_base().clone();
+        opts.set_pass_through(true)
+            .set_plus_prefix(true);
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Possible values
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+mod possible_values {
+    use super::*;
+
+    const COLORS: &[&str] = &[ "always", "never", "auto" ];
+
+    /// Check a range of inputs
+    #[test]
+    fn basic() {
+        let args = arg_list!(
+            "--color=never",    // Exact
+            "--color", "al",    // Abbreviated, in next arg
+            "--col=n",          // Abbreviated name and value
+            "--color=a",        // Ambiguous
+            "--color=",         // Empty
+            "--color", "blue",  // Invalid, in next arg
+            "--hah=blue",       // Unrestricted
+        );
+        let expected = expected!(
+            error: true,
+            warn: false,
+            [
+                expected_item!(0, LongWithData, "color", "never", DataLocation::SameArg),
+                expected_item!(1, LongWithData, "color", "al", "always", DataLocation::NextArg),
+                expected_item!(3, LongWithData, "color", "n", "never", DataLocation::SameArg),
+                expected_item!(4, LongInvalidData, "color", "a", DataLocation::SameArg, COLORS),
+                expected_item!(5, LongInvalidData, "color", "", DataLocation::SameArg, COLORS),
+                expected_item!(6, LongInvalidData, "color", "blue", DataLocation::NextArg,
+                    COLORS),
+                expected_item!(8, LongWithData, "hah", "blue", DataLocation::SameArg),
+            ]
+        );
+        let mut opts = get_base().to_extendible();
+        opts.long.push(gong_longopt!("color", true; possible_values: COLORS));
+        let opts = opts.as_fixed();
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check abbreviated values are not accepted with abbreviations disabled
+    #[test]
+    fn no_abbreviations() {
+        let args = arg_list!("--color=al", "--color=auto");
+        let expected = expected!(
+            error: true,
+            warn: false,
+            [
+                expected_item!(0, LongInvalidData, "color", "al", DataLocation::SameArg, COLORS),
+                expected_item!(1, LongWithData, "color", "auto", DataLocation::SameArg),
+            ]
+        );
+        let mut opts = get_base().to_extendible();
+        opts.long.push(gong_longopt!("color", true; possible_values: COLORS));
+        opts.set_allow_abbreviations(false);
+        let opts = opts.as_fixed();
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check an exact match is found after an ambiguous abbreviation
+    #[test]
+    fn exact_after_ambiguity() {
+        let args = arg_list!("--color=al", "--color=alp", "--color=a");
+        let values: &[&str] = &[ "alpha", "always", "al" ];
+        let expected = expected!(
+            error: true,
+            warn: false,
+            [
+                expected_item!(0, LongWithData, "color", "al", DataLocation::SameArg),
+                expected_item!(1, LongWithData, "color", "alp", "alpha", DataLocation::SameArg),
+                expected_item!(2, LongInvalidData, "color", "a", DataLocation::SameArg, values),
+            ]
+        );
+        let mut opts = get_base().to_extendible();
+        opts.long.push(gong_longopt!("color", true; possible_values: values));
+        let opts = opts.as_fixed();
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check the option’s own abbreviation controls apply to values
+    #[test]
+    fn option_abbreviation_controls() {
+        let args = arg_list!("--color=al", "--mode=fa", "--mode=fas");
+        let modes: &[&str] = &[ "fast", "safe" ];
+        let expected = expected!(
+            error: true,
+            warn: false,
+            [
+                expected_item!(0, LongInvalidData, "color", "al", DataLocation::SameArg, COLORS),
+                expected_item!(1, LongInvalidData, "mode", "fa", DataLocation::SameArg, modes),
+                expected_item!(2, LongWithData, "mode", "fas", "fast", DataLocation::SameArg),
+            ]
+        );
+        let mut opts = get_base().to_extendible();
+        opts.long.push(gong_longopt!("color", true; possible_values: COLORS,
+            allow_abbreviation: false));
+        opts.long.push(gong_longopt!("mode", true; possible_values: modes, min_abbreviation: 3));
+        let opts = opts.as_fixed();
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Option status
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+mod option_status {
+    use super::*;
+
+    static STATUS_OPTS: OptionSet = gong_option_set_fixed!(
+        [
+            gong_longopt!("secret", false; status: OptionStatus::Hidden),
+            gong_longopt!("colour", true),
+            gong_longopt!("color", true; status: OptionStatus::Deprecated(Some("--colour"))),
+            gong_longopt!("old", false; status: OptionStatus::Deprecated(None), negatable: true),
+            gong_longopt!("legacy", false; status: OptionStatus::Legacy),
+            gong_longopt!("legacy-data", true; status: OptionStatus::Legacy),
+        ],
+        [
+            gong_shortopt!('x'),
+            gong_shortopt!('d', false; status: OptionStatus::Deprecated(Some("-x"))),
+            gong_shortopt!('D', true; status: OptionStatus::Deprecated(None)),
+            gong_shortopt!('l', false; status: OptionStatus::Legacy),
+            gong_shortopt!('L', true; status: OptionStatus::Legacy),
+        ];
+        plus_prefix: true
+    );
+
+    /// Check a range of inputs
+    #[test]
+    fn basic() {
+        let args = arg_list!(
+            "--secret",             // Hidden
+            "--color=red",          // Deprecated, with replacement
+            "--old",                // Deprecated, without replacement
+            "++old",                // Deprecated, negated
+            "--legacy",             // Legacy
+            "--legacy-data", "x",   // Legacy, with data in next arg
+            "--legacy-d=y",         // Legacy, abbreviated, with data in same arg
+            "-xdl",                 // Short set with deprecated and legacy
+            "-Ddata",               // Deprecated, with data in same arg
+            "-lLdata",              // Legacy, with data in same arg
+            "-L", "data",           // Legacy, with data in next arg
+            "+ld",                  // Negated
+            "abc",                  // Non-option
+        );
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, Long, "secret"),
+                expected_item!(1, LongWithData, "color", "red", DataLocation::SameArg),
+                expected_item!(1, LongDeprecated, "color", Some("--colour")),
+                expected_item!(2, Long, "old"),
+                expected_item!(2, LongDeprecated, "old", None),
+                expected_item!(3, LongNegated, "old"),
+                expected_item!(3, LongDeprecated, "old", None),
+                expected_item!(8, Short, 'x'),
+                expected_item!(8, Short, 'd'),
+                expected_item!(8, ShortDeprecated, 'd', Some("-x")),
+                expected_item!(9, ShortWithData, 'D', "data", DataLocation::SameArg),
+                expected_item!(9, ShortDeprecated, 'D', None),
+                expected_item!(13, ShortNotNegatable, 'd'),
+                expected_item!(14, NonOption, "abc"),
+            ]
+        );
+        let analysis = STATUS_OPTS.process(&args);
+        assert_eq!(analysis.arg_roles(args.len()), vec![
+            ArgRole::Option,
+            ArgRole::Option,
+            ArgRole::Option,
+            ArgRole::Option,
+            ArgRole::Ignored,
+            ArgRole::Ignored,
+            ArgRole::Ignored,
+            ArgRole::Ignored,
+            ArgRole::Option,
+            ArgRole::Option,
+            ArgRole::Ignored,
+            ArgRole::Ignored,
+            ArgRole::Ignored,
+            ArgRole::Option,
+            ArgRole::NonOption,
+        ]);
+        check_result(&Actual(analysis), &expected);
+    }
+
+    /// Check visibility
+    #[test]
+    fn is_hidden() {
+        assert!(!OptionStatus::Normal.is_hidden());
+        assert!(OptionStatus::Hidden.is_hidden());
+        assert!(!OptionStatus::Deprecated(None).is_hidden());
+        assert!(OptionStatus::Legacy.is_hidden());
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Custom syntax
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+mod custom_syntax {
+    use super::*;
+
+    /// A made up syntax, with multiple long option prefixes
+    static SYNTAX: Syntax = Syntax {
+        long_prefixes: &["//", "-"],
+        short_prefix: Some("~"),
+        early_terminator: Some("---"),
+        data_separators: &[':'],
+        negated_long_prefix: Some("!!"),
+        negated_short_prefix: Some("!"),
+        case_insensitive: false,
+        polarity_suffixes: false,
+        help_alias: None,
+        long_first: false,
+    };
+
+    /// Check a range of inputs
+    #[test]
+    fn basic() {
+        let args = arg_list!(
+            "abc",          // Non-option
+            "//help",       // Known option
+            "-foo",         // Known option, second prefix
+            "-hah:x",       // With data, custom separator
+            "-hah=x",       // Unknown, `=` is not a separator
+            "~hx",          // Short option set
+            "--help",       // Long, with name `-help`
+            "!!foo",        // Negated long
+            "!x",           // Negated short
+            "!!",           // Non-option
+            "---",          // Early terminator
+            "-foo",         // Non-option
+        );
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, NonOption, "abc"),
+                expected_item!(1, Long, "help"),
+                expected_item!(2, Long, "foo"),
+                expected_item!(3, LongWithData, "hah", "x", DataLocation::SameArg),
+                expected_item!(4, UnknownLong, "hah=x"),
+                expected_item!(5, Short, 'h'),
+                expected_item!(5, Short, 'x'),
+                expected_item!(6, UnknownLong, "-help"),
+                expected_item!(7, LongNegated, "foo"),
+                expected_item!(8, ShortNegated, 'x'),
+                expected_item!(9, NonOption, "!!"),
+                expected_item!(10, EarlyTerminator),
+                expected_item!(11, NonOption, "-foo"),
+            ]
+        );
+        let mut opts = gong_option_set!(
+            vec![
+                gong_longopt!("help"),
+                gong_longopt!("foo", false; negatable: true),
+                gong_longopt!("hah", true),
+            ],
+            vec![
+                gong_shortopt!('h'),
+                gong_shortopt!('x', false; negatable: true),
+            ]
+        );
+        opts.set_mode(OptionsMode::Custom(&SYNTAX))
+            .set_plus_prefix(true);
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check a multi-byte data separator
+    #[test]
+    fn multibyte_separator() {
+        static SYNTAX: Syntax = Syntax { data_separators: &['→'], ..Syntax::STANDARD };
+        let args = arg_list!("--hah→x", "--foo→");
+        let expected = expected!(
+            error: false,
+            warn: false,
+            [
+                expected_item!(0, LongWithData, "hah", "x", DataLocation::SameArg),
+                expected_item!(1, Long, "foo"),
+            ]
+        );
+        let mut opts = get_base().clone();
+        opts.set_mode(OptionsMode::Custom(&SYNTAX));
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check the built-in modes use the predefined syntax descriptions
+    #[test]
+    fn predefined() {
+        assert_eq!(OptionsMode::Standard.syntax(), &Syntax::STANDARD);
+        assert_eq!(OptionsMode::Alternate.syntax(), &Syntax::ALTERNATE);
+        assert_eq!(OptionsMode::Dos.syntax(), &Syntax::DOS);
+        assert_eq!(OptionsMode::Custom(&Syntax::DOS).syntax(), &Syntax::DOS);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Negative numbers
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+mod negative_numbers {
+    use super::*;
+
+    /// Check numbers are taken as *non-options* when enabled
+    #[test]
+    fn enabled() {
+        let args = arg_list!("-5", "-3.14", "-.5", "-1e6", "-2E-3", "-x", "-5x", "-inf", "--5",
+            "-1.2.3", "-o", "-7");
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, NonOption, "-5"),
+                expected_item!(1, NonOption, "-3.14"),
+                expected_item!(2, NonOption, "-.5"),
+                expected_item!(3, NonOption, "-1e6"),
+                expected_item!(4, NonOption, "-2E-3"),
+                expected_item!(5, Short, 'x'),
+                expected_item!(6, UnknownShort, '5'),
+                expected_item!(6, Short, 'x'),
+                expected_item!(7, UnknownShort, 'i'),
+                expected_item!(7, UnknownShort, 'n'),
+                expected_item!(7, UnknownShort, 'f'),
+                expected_item!(8, UnknownLong, "5"),
+                expected_item!(9, UnknownShort, '1'),
+                expected_item!(9, UnknownShort, '.'),
+                expected_item!(9, UnknownShort, '2'),
+                expected_item!(9, UnknownShort, '.'),
+                expected_item!(9, UnknownShort, '3'),
+                expected_item!(10, ShortWithData, 'o', "-7", DataLocation::NextArg),
+            ]
+        );
+        let mut opts = get_base().clone();
+        opts.set_negative_numbers(true);
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check numbers are short option sets when disabled (the default)
+    #[test]
+    fn disabled() {
+        let args = arg_list!("-5", "-3.1");
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, UnknownShort, '5'),
+                expected_item!(1, UnknownShort, '3'),
+                expected_item!(1, UnknownShort, '.'),
+                expected_item!(1, UnknownShort, '1'),
+            ]
+        );
+        check_result(&Actual(get_base().process(&args)), &expected);
+    }
+
+    /// Check alternate mode, and plus signed numbers with plus prefix support
+    #[test]
+    fn other_forms() {
+        let args = arg_list!("-5", "-foo", "+5", "+x");
+        let expected = expected!(
+            error: false,
+            warn: true,
+            [
+                expected_item!(0, NonOption, "-5"),
+                expected_item!(1, Long, "foo"),
+                expected_item!(2, NonOption, "+5"),
+                expected_item!(3, UnknownLong, "x"),
+            ]
+        );
+        let mut opts = get_base().clone();
+        opts.set_mode(OptionsMode::Alternate)
+            .set_negative_numbers(true)
+            .set_plus_prefix(true);
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+
+    /// Check the validation report warns of conflicting digit short options, without them making
+    /// the set invalid
+    #[test]
+    fn validation() {
+        let mut opts = gong_option_set!(vec![], vec![ gong_shortopt!('1'), gong_shortopt!('a') ]);
+        assert_eq!(opts.validation_report(), vec![]);
+        opts.set_negative_numbers(true);
+        assert!(opts.is_valid());
+        assert_eq!(opts.as_fixed().first_flaw(), None);
+        assert_eq!(opts.validation_report(), vec![ValidationFinding {
+            location: FindingLocation::Short(0, '1'),
+            kind: FindingKind::Lint(OptionLint::DigitWithNegativeNumbers),
+        }]);
+
+        // Still matched within a set
+        let args = arg_list!("-a1", "-1");
+        let expected = expected!(
+            error: false,
+            warn: false,
+            [
+                expected_item!(0, Short, 'a'),
+                expected_item!(0, Short, '1'),
+                expected_item!(1, NonOption, "-1"),
+            ]
+        );
+        check_result(&Actual(opts.process(&args)), &expected);
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Testing item source locations and argument roles
+
+#[macro_use]
+extern crate gong;
+
+#[allow(unused_macros)]
+#[allow(dead_code)] //Mod shared across test crates
+#[macro_use]
+mod common;
+
+use std::ops::Range;
+use gong::analysis::*;
+use gong::options::*;
+use common::get_base;
+
+/// Construct an `ItemSpan`
+fn span<'a>(arg: usize, range: Range<usize>, text: &'a str, data: Option<(usize, Range<usize>)>)
+    -> ItemSpan<'a>
+{
+    ItemSpan { arg, range, text, data }
+}
+
+/// Check spans and roles over a range of inputs
+#[test]
+fn basic() {
+    let args = arg_list!(
+        "abc",          // Non-option
+        "--fooba",      // Abbreviated
+        "-hzx",         // Short set, with unknown
+        "-ofile",       // Short with data in same arg
+        "--hah=val",    // Long with data in same arg
+        "--hah",        // Long with data in next arg
+        "next",         // Data
+        "-❤Ɛ",          // Multi-byte shorts, with data in next arg
+        "d",            // Data
+        "--foo=x",      // Unexpected data
+        "--",           // Early terminator
+        "-x",           // Non-option
+    );
+    let analysis = get_base().process(&args);
+    assert_eq!(analysis.item_spans(&args, OptionsMode::Standard), vec![
+        span(0, 0..3, "abc", None),
+        span(1, 2..7, "fooba", None),
+        span(2, 1..2, "h", None),
+        span(2, 2..3, "z", None),
+        span(2, 3..4, "x", None),
+        span(3, 1..2, "o", Some((3, 2..6))),
+        span(4, 2..5, "hah", Some((4, 6..9))),
+        span(5, 2..5, "hah", Some((6, 0..4))),
+        span(7, 1..4, "❤", None),
+        span(7, 4..6, "Ɛ", Some((8, 0..1))),
+        span(9, 2..5, "foo", Some((9, 6..7))),
+        span(10, 0..2, "--", None),
+        span(11, 0..2, "-x", None),
+    ]);
+    assert_eq!(analysis.arg_roles(args.len()), vec![
+        ArgRole::NonOption,
+        ArgRole::Option,
+        ArgRole::Option,
+        ArgRole::Option,
+        ArgRole::Option,
+        ArgRole::Option,
+        ArgRole::Data(7),
+        ArgRole::Option,
+        ArgRole::Data(9),
+        ArgRole::Option,
+        ArgRole::Terminator,
+        ArgRole::NonOption,
+    ]);
+}
+
+/// Check spans with other modes and settings
+#[test]
+fn other_modes() {
+    let mut opts = get_base().clone();
+
+    opts.set_mode(OptionsMode::Dos);
+    // Polarity suffixes are excluded
+    let args = arg_list!("/HAH:x", "/foo-", "/foo+", "/fooba-");
+    assert_eq!(opts.process(&args).item_spans(&args, OptionsMode::Dos), vec![
+        span(0, 1..4, "HAH", Some((0, 5..6))),
+        span(1, 1..4, "foo", None),
+        span(2, 1..4, "foo", None),
+        span(3, 1..6, "fooba", None),
+    ]);
+
+    opts.set_mode(OptionsMode::LongOnly);
+    let args = arg_list!("-vers", "-xo", "d");
+    assert_eq!(opts.process(&args).item_spans(&args, OptionsMode::LongOnly), vec![
+        span(0, 1..5, "vers", None),
+        span(1, 1..2, "x", None),
+        span(1, 2..3, "o", Some((2, 0..1))),
+    ]);
+
+    opts.set_mode(OptionsMode::Standard)
+        .set_plus_prefix(true);
+    let args = arg_list!("++foo", "+hx", "--=a");
+    assert_eq!(opts.process(&args).item_spans(&args, OptionsMode::Standard), vec![
+        span(0, 2..5, "foo", None),
+        span(1, 1..2, "h", None),
+        span(1, 2..3, "x", None),
+        span(2, 2..2, "", None),
+    ]);
+}
+
+/// Check deprecation warnings share the location of their option, and legacy option arguments
+#[test]
+fn option_status() {
+    let opts = gong_option_set_fixed!(
+        [ gong_longopt!("old", true; status: OptionStatus::Deprecated(None)) ],
+        [
+            gong_shortopt!('x'),
+            gong_shortopt!('d', false; status: OptionStatus::Deprecated(None)),
+            gong_shortopt!('L', true; status: OptionStatus::Legacy),
+        ]
+    );
+    let args = arg_list!("--old=a", "-dx", "-L", "b");
+    let analysis = opts.process(&args);
+    assert_eq!(analysis.item_spans(&args, OptionsMode::Standard), vec![
+        span(0, 2..5, "old", Some((0, 6..7))),
+        span(0, 2..5, "old", None),
+        span(1, 1..2, "d", None),
+        span(1, 1..2, "d", None),
+        span(1, 2..3, "x", None),
+    ]);
+    assert_eq!(analysis.arg_roles(args.len()), vec![
+        ArgRole::Option,
+        ArgRole::Option,
+        ArgRole::Ignored,
+        ArgRole::Ignored,
+    ]);
+}
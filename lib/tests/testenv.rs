@@ -25,7 +25,8 @@ use common::get_base;
 /// public API).
 #[test]
 fn check_constants() {
-    let test = gong_option_set!(vec![], vec![], common::MODE_DEFAULT, common::ABBR_SUP_DEFAULT);
+    let test = gong_option_set!(vec![], vec![], common::MODE_DEFAULT, common::ABBR_SUP_DEFAULT;
+        ordering: common::ORDERING_DEFAULT);
     let cmp: OptionSetEx = Default::default();
     assert_eq!(test, cmp);
 }
@@ -0,0 +1,198 @@
+// Copyright 2026 Lyndon Brown
+//
+// This file is part of the `gong` command-line argument processing library.
+//
+// Licensed under the MIT license or the Apache license (version 2.0), at your option. You may not
+// copy, modify, or distribute this file except in compliance with said license. You can find copies
+// of these licenses either in the LICENSE-MIT and LICENSE-APACHE files, or alternatively at
+// <http://opensource.org/licenses/MIT> and <http://www.apache.org/licenses/LICENSE-2.0>
+// respectively.
+
+//! Testing option value resolution (the query layer over an analysis)
+
+// Construction macros cannot know when every attribute has been given
+#![allow(clippy::needless_update)]
+
+#[macro_use]
+extern crate gong;
+
+#[allow(unused_macros)]
+#[allow(dead_code)] //Mod shared across test crates
+#[macro_use]
+mod common;
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use gong::options::*;
+use gong::values::*;
+
+/// A set with some options bound to environment variables
+static ENV_OPTS: OptionSet = gong_option_set_fixed!(
+    [
+        gong_longopt!("token", true; env: Some("MYTOOL_TOKEN")),
+        gong_longopt!("verbose", false; env: Some("MYTOOL_VERBOSE")),
+        gong_longopt!("user", true; env: Some("MYTOOL_USER"), default: Some("guest")),
+        gong_longopt!("foo"),
+        gong_longopt!("level", true; default: Some("1")),
+        gong_longopt!("color", false; env: Some("MYTOOL_COLOR"), negatable: true),
+        gong_longopt!("mode", true; env: Some("MYTOOL_MODE"), possible_values: &["fast", "safe"]),
+    ],
+    []
+);
+
+/// Check values from the command line
+#[test]
+fn command_line() {
+    let args = arg_list!("--foo", "--token=abc", "--bar", "--token", "def");
+    let analysis = ENV_OPTS.process(&args);
+    let values = OptionValues::from_analysis(&analysis);
+    assert_eq!(values.values, vec![
+        OptionValue { name: "foo", data: None, negated: false,
+            source: ValueSource::CommandLine(0) },
+        OptionValue { name: "token", data: Some(Cow::Borrowed("abc")),
+            negated: false, source: ValueSource::CommandLine(1) },
+        OptionValue { name: "token", data: Some(Cow::Borrowed("def")),
+            negated: false, source: ValueSource::CommandLine(3) },
+    ]);
+    // The last instance wins
+    assert_eq!(values.get_data("token"), Some("def"));
+    assert!(values.contains("foo"));
+    assert!(!values.contains("bar"));
+    assert_eq!(values.get("verbose"), None);
+}
+
+/// Check environment fallback, with a fake environment
+#[test]
+fn env_fallback() {
+    let env = [
+        ("MYTOOL_TOKEN", "from-env"),
+        ("MYTOOL_VERBOSE", "1"),
+        ("MYTOOL_USER", "nobody"),
+    ];
+
+    // Command line takes precedence
+    let args = arg_list!("--user", "me");
+    let analysis = ENV_OPTS.process(&args);
+    let mut values = OptionValues::from_analysis(&analysis);
+    values.add_env(&ENV_OPTS, &env[..]);
+    assert_eq!(values.values, vec![
+        OptionValue { name: "user", data: Some(Cow::Borrowed("me")),
+            negated: false, source: ValueSource::CommandLine(0) },
+        OptionValue { name: "token", data: Some(Cow::Owned(String::from("from-env"))),
+            negated: false, source: ValueSource::Environment("MYTOOL_TOKEN") },
+        OptionValue { name: "verbose", data: None,
+            negated: false, source: ValueSource::Environment("MYTOOL_VERBOSE") },
+    ]);
+    assert_eq!(values.get_data("token"), Some("from-env"));
+    assert_eq!(values.get_data("user"), Some("me"));
+}
+
+/// Check variables not holding one of the possible values are rejected
+#[test]
+fn env_invalid() {
+    let env = [("MYTOOL_MODE", "bogus")];
+    let args: [&str; 0] = [];
+    let analysis = ENV_OPTS.process(&args);
+    let mut values = OptionValues::from_analysis(&analysis);
+    values.add_env(&ENV_OPTS, &env[..]);
+    assert_eq!(values.get("mode"), None);
+    assert_eq!(values.invalid, vec![
+        OptionValue { name: "mode", data: Some(Cow::Owned(String::from("bogus"))),
+            negated: false, source: ValueSource::Environment("MYTOOL_MODE") },
+    ]);
+
+    // Possible values must be given in full
+    let env = [("MYTOOL_MODE", "fa")];
+    let mut values = OptionValues::from_analysis(&analysis);
+    values.add_env(&ENV_OPTS, &env[..]);
+    assert_eq!(values.get("mode"), None);
+    assert_eq!(values.invalid.len(), 1);
+
+    let env = [("MYTOOL_MODE", "fast")];
+    let mut values = OptionValues::from_analysis(&analysis);
+    values.add_env(&ENV_OPTS, &env[..]);
+    assert_eq!(values.get_data("mode"), Some("fast"));
+    assert!(values.invalid.is_empty());
+}
+
+/// Check unset and empty variables
+#[test]
+fn env_unset_or_empty() {
+    let mut env = HashMap::new();
+    env.insert(String::from("MYTOOL_VERBOSE"), String::new());
+    env.insert(String::from("MYTOOL_TOKEN"), String::new());
+
+    let args: [&str; 0] = [];
+    let analysis = ENV_OPTS.process(&args);
+    let mut values = OptionValues::from_analysis(&analysis);
+    values.add_env(&ENV_OPTS, &env);
+    // Empty is a valid data value, but does not set a flag
+    assert_eq!(values.values, vec![
+        OptionValue { name: "token", data: Some(Cow::Owned(String::new())),
+            negated: false, source: ValueSource::Environment("MYTOOL_TOKEN") },
+    ]);
+}
+
+/// Check default values, which apply only where no other source gives a value
+#[test]
+fn defaults() {
+    let env = [("MYTOOL_USER", "nobody")];
+
+    let args = arg_list!("--level=3");
+    let analysis = ENV_OPTS.process(&args);
+    let mut values = OptionValues::from_analysis(&analysis);
+    values.add_defaults(&ENV_OPTS);
+    values.add_env(&ENV_OPTS, &env[..]);
+    assert_eq!(values.values, vec![
+        OptionValue { name: "level", data: Some(Cow::Borrowed("3")),
+            negated: false, source: ValueSource::CommandLine(0) },
+        OptionValue { name: "user", data: Some(Cow::Owned(String::from("nobody"))),
+            negated: false, source: ValueSource::Environment("MYTOOL_USER") },
+    ]);
+
+    let args: [&str; 0] = [];
+    let analysis = ENV_OPTS.process(&args);
+    let mut values = OptionValues::from_analysis(&analysis);
+    values.add_defaults(&ENV_OPTS);
+    assert_eq!(values.values, vec![
+        OptionValue { name: "user", data: Some(Cow::Borrowed("guest")),
+            negated: false, source: ValueSource::Default },
+        OptionValue { name: "level", data: Some(Cow::Borrowed("1")),
+            negated: false, source: ValueSource::Default },
+    ]);
+    assert_eq!(values.get_data("level"), Some("1"));
+}
+
+/// Check that negated options given on the command line are not overridden by other sources
+#[test]
+fn negated() {
+    let env = [("MYTOOL_COLOR", "1"), ("MYTOOL_VERBOSE", "1")];
+    let mut opts = ENV_OPTS.to_extendible();
+    opts.set_plus_prefix(true);
+    let opts = opts.as_fixed();
+
+    let args = arg_list!("++color");
+    let analysis = opts.process(&args);
+    let mut values = OptionValues::from_analysis(&analysis);
+    values.add_env(&opts, &env[..]);
+    assert_eq!(values.get("color"), Some(&OptionValue { name: "color", data: None, negated: true,
+        source: ValueSource::CommandLine(0) }));
+    assert!(!values.contains("color"));
+    assert!(values.contains("verbose"));
+
+    // The last instance wins
+    let args = arg_list!("++color", "--color");
+    let values = OptionValues::from_analysis(&opts.process(&args));
+    assert!(values.contains("color"));
+    let args = arg_list!("--color", "++color");
+    let values = OptionValues::from_analysis(&opts.process(&args));
+    assert!(!values.contains("color"));
+}
+
+/// Check source descriptions
+#[test]
+fn source_display() {
+    assert_eq!(ValueSource::CommandLine(3).to_string(), "command line (argument 3)");
+    assert_eq!(ValueSource::Environment("FOO").to_string(), "environment variable `FOO`");
+    assert_eq!(ValueSource::Default.to_string(), "default");
+}